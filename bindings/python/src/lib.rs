@@ -1,21 +1,58 @@
-use chrono::NaiveDate;
-use human_chrono_parser::{locales::Locale, HumanDateExpr};
+// pyo3 0.22 macro expansion trips this lint on every `PyResult` return type.
+#![allow(clippy::useless_conversion)]
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, Weekday};
+use human_chrono_parser::{
+    chrono_tz::Tz, locales::Locale, ExtractOptions, HumanDateExpr, ResolveContext, ResolveOptions,
+    Resolved, WeekMode, YearPolicy,
+};
 use pyo3::{
+    create_exception,
     exceptions::{PyRuntimeError, PyValueError},
     prelude::*,
 };
 
+// Parse failures used to raise a plain `RuntimeError`, so `except RuntimeError`
+// still catches them.
+create_exception!(human_chrono_parser, ParseError, PyRuntimeError);
+
 #[pyfunction]
-fn parse(input: String, locale_name: String) -> PyResult<PyHumanDateExpr> {
+fn parse(py: Python<'_>, input: String, locale_name: String) -> PyResult<PyHumanDateExpr> {
     let locale = get_locale(&locale_name)?;
     human_chrono_parser::parse(&mut input.as_str(), &locale)
-        .map(|expr| PyHumanDateExpr { inner: expr })
-        .map_err(|err| PyRuntimeError::new_err(format!("{}", err)))
+        .map(|expr| PyHumanDateExpr {
+            inner: expr,
+            locale,
+        })
+        .map_err(|err| parse_error(py, &input, err))
+}
+
+/// Raises `ParseError` with `offset`, `expected` and `suggestion` attributes.
+/// Offsets are converted to char offsets, as Python strings index by character.
+fn parse_error(py: Python<'_>, input: &str, err: human_chrono_parser::ParseError) -> PyErr {
+    let char_offset = |offset: usize| input[..offset].chars().count();
+    let py_err = ParseError::new_err(err.to_string());
+    let value = py_err.value_bound(py);
+    let expected: Vec<String> = err.expected().iter().map(|e| e.to_string()).collect();
+    let suggestion = err
+        .suggestion()
+        .map(|s| (char_offset(s.start), char_offset(s.end), s.replacement));
+    let attributes = value
+        .setattr("offset", char_offset(err.offset()))
+        .and_then(|_| value.setattr("expected", expected))
+        .and_then(|_| value.setattr("suggestion", suggestion));
+    match attributes {
+        Ok(()) => py_err,
+        Err(err) => err,
+    }
 }
 
 fn get_locale(locale_name: &String) -> PyResult<Locale> {
     match locale_name.as_ref() {
-        "pt-BR" => Ok(Locale::BrazilianPortuguese),
+        "pt" | "pt-BR" => Ok(Locale::BrazilianPortuguese),
+        "pt-PT" => Ok(Locale::EuropeanPortuguese),
+        "en" | "en-US" => Ok(Locale::English),
+        "es" => Ok(Locale::Spanish),
         _ => Err(PyValueError::new_err(format!(
             "Unknown locale: {}",
             locale_name
@@ -23,33 +60,224 @@ fn get_locale(locale_name: &String) -> PyResult<Locale> {
     }
 }
 
+fn get_resolve_options(
+    locale: &Locale,
+    year_policy: &str,
+    week_start: Option<&str>,
+    week_mode: &str,
+) -> PyResult<ResolveOptions> {
+    let year_policy = match year_policy {
+        "prefer_future" => YearPolicy::PreferFuture,
+        "prefer_past" => YearPolicy::PreferPast,
+        "nearest" => YearPolicy::Nearest,
+        "current_year" => YearPolicy::CurrentYear,
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "Unknown year policy: {}",
+                year_policy
+            )))
+        }
+    };
+    let week_start: Weekday = match week_start {
+        Some(week_start) => week_start
+            .parse()
+            .map_err(|_| PyValueError::new_err(format!("Unknown weekday: {}", week_start)))?,
+        None => locale.week_start(),
+    };
+    let week_mode = match week_mode {
+        "rolling" => WeekMode::Rolling,
+        "next_occurrence" => WeekMode::NextOccurrence,
+        "calendar" => WeekMode::Calendar,
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "Unknown week mode: {}",
+                week_mode
+            )))
+        }
+    };
+    Ok(ResolveOptions {
+        year_policy,
+        week_start,
+        week_mode,
+        ..ResolveOptions::for_locale(locale)
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (input, locale_name, word_boundaries = true))]
+fn extract_all(
+    input: String,
+    locale_name: String,
+    word_boundaries: bool,
+) -> PyResult<Vec<PyHumanDateExpr>> {
+    let locale = get_locale(&locale_name)?;
+    let options = ExtractOptions { word_boundaries };
+    Ok(
+        human_chrono_parser::extract_all_with(&mut input.as_str(), &locale, &options)
+            .into_iter()
+            .map(|expr| PyHumanDateExpr {
+                inner: expr,
+                locale,
+            })
+            .collect(),
+    )
+}
+
 #[pyfunction]
-fn extract_all(input: String, locale_name: String) -> PyResult<Vec<PyHumanDateExpr>> {
+#[pyo3(signature = (input, locale_name, word_boundaries = true))]
+fn extract_matches(
+    input: String,
+    locale_name: String,
+    word_boundaries: bool,
+) -> PyResult<Vec<PyMatch>> {
     let locale = get_locale(&locale_name)?;
+    let options = ExtractOptions { word_boundaries };
     Ok(
-        human_chrono_parser::extract_all(&mut input.as_str(), &locale)
+        human_chrono_parser::extract_matches_with(&mut input.as_str(), &locale, &options)
             .into_iter()
-            .map(|expr| PyHumanDateExpr { inner: expr })
+            .map(|m| PyMatch {
+                expr: PyHumanDateExpr {
+                    inner: m.expr,
+                    locale,
+                },
+                start: m.char_start,
+                end: m.char_end,
+                byte_start: m.start,
+                byte_end: m.end,
+                text: m.text.to_string(),
+            })
             .collect(),
     )
 }
 
+/// Python strings are indexed by character, so `start` and `end` are char
+/// offsets here; the byte offsets are kept as `byte_start` and `byte_end`.
+#[pyclass(name = "Match", get_all)]
+struct PyMatch {
+    expr: PyHumanDateExpr,
+    start: usize,
+    end: usize,
+    byte_start: usize,
+    byte_end: usize,
+    text: String,
+}
+
 #[pyclass(name = "HumanDateExpr", eq)]
-#[derive(PartialEq)]
+#[derive(Clone, PartialEq)]
 struct PyHumanDateExpr {
     inner: HumanDateExpr,
+    /// The locale the expression was parsed in, whose week start and holidays
+    /// are the defaults when resolving it.
+    locale: Locale,
 }
 
 #[pymethods]
 impl PyHumanDateExpr {
-    pub fn relative_to(&self, now: NaiveDate) -> PyResult<Option<NaiveDate>> {
-        Ok(self.inner.relative_to(&now))
+    #[pyo3(signature = (now, year_policy = "prefer_future", week_start = None, week_mode = "rolling"))]
+    pub fn relative_to(
+        &self,
+        now: NaiveDate,
+        year_policy: &str,
+        week_start: Option<&str>,
+        week_mode: &str,
+    ) -> PyResult<Option<NaiveDate>> {
+        let options = get_resolve_options(&self.locale, year_policy, week_start, week_mode)?;
+        Ok(self.inner.try_relative_to_with(&now, &options).ok())
+    }
+
+    #[pyo3(signature = (now, year_policy = "prefer_future", week_start = None, week_mode = "rolling"))]
+    pub fn relative_to_datetime(
+        &self,
+        now: NaiveDateTime,
+        year_policy: &str,
+        week_start: Option<&str>,
+        week_mode: &str,
+    ) -> PyResult<Option<NaiveDateTime>> {
+        let options = get_resolve_options(&self.locale, year_policy, week_start, week_mode)?;
+        Ok(self
+            .inner
+            .try_relative_to_datetime_with(&now, &options)
+            .ok())
+    }
+
+    /// Resolves against `now` as seen in `timezone`, an IANA name like
+    /// "America/Sao_Paulo", and returns an aware datetime with that zone's
+    /// offset on the resolved date.
+    #[pyo3(signature = (now, timezone, year_policy = "prefer_future", week_start = None, week_mode = "rolling"))]
+    pub fn relative_to_zoned(
+        &self,
+        now: DateTime<FixedOffset>,
+        timezone: &str,
+        year_policy: &str,
+        week_start: Option<&str>,
+        week_mode: &str,
+    ) -> PyResult<Option<DateTime<FixedOffset>>> {
+        let timezone: Tz = timezone
+            .parse()
+            .map_err(|_| PyValueError::new_err(format!("Unknown timezone: {}", timezone)))?;
+        let options = get_resolve_options(&self.locale, year_policy, week_start, week_mode)?;
+        Ok(self
+            .inner
+            .try_relative_to_zoned_with(&now.with_timezone(&timezone), &options)
+            .ok()
+            .map(|datetime| datetime.fixed_offset()))
+    }
+
+    /// The first and last days of a range or period like "next week", or the
+    /// same day twice for any other expression.
+    #[pyo3(signature = (now, year_policy = "prefer_future", week_start = None, week_mode = "rolling"))]
+    pub fn range_relative_to(
+        &self,
+        now: NaiveDate,
+        year_policy: &str,
+        week_start: Option<&str>,
+        week_mode: &str,
+    ) -> PyResult<Option<(NaiveDate, NaiveDate)>> {
+        let options = get_resolve_options(&self.locale, year_policy, week_start, week_mode)?;
+        Ok(self.inner.try_range_relative_to_with(&now, &options).ok())
+    }
+
+    /// A `date`, a `datetime` or a `(date, date)` range, depending on what the
+    /// expression names, or `None` if it can't be resolved. With a `timezone`,
+    /// an IANA name like "America/Sao_Paulo", `now` is the local time there and
+    /// datetimes are aware.
+    #[pyo3(signature = (now, year_policy = "prefer_future", week_start = None, week_mode = "rolling", timezone = None))]
+    pub fn resolve(
+        &self,
+        py: Python<'_>,
+        now: NaiveDateTime,
+        year_policy: &str,
+        week_start: Option<&str>,
+        week_mode: &str,
+        timezone: Option<&str>,
+    ) -> PyResult<PyObject> {
+        let timezone: Option<Tz> = timezone
+            .map(|timezone| {
+                timezone
+                    .parse()
+                    .map_err(|_| PyValueError::new_err(format!("Unknown timezone: {}", timezone)))
+            })
+            .transpose()?;
+        let context = ResolveContext {
+            now,
+            timezone,
+            options: get_resolve_options(&self.locale, year_policy, week_start, week_mode)?,
+        };
+        Ok(match self.inner.resolve(&context) {
+            Ok(Resolved::Date(date)) => date.into_py(py),
+            Ok(Resolved::DateTime(datetime)) => datetime.into_py(py),
+            Ok(Resolved::ZonedDateTime(datetime)) => datetime.into_py(py),
+            Ok(Resolved::Range(start, end)) => (start, end).into_py(py),
+            Err(_) => py.None(),
+        })
     }
 }
 
 #[pymodule(name = "human_chrono_parser")]
 fn human_chrono_parser_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("ParseError", m.py().get_type_bound::<ParseError>())?;
     m.add_function(wrap_pyfunction!(parse, m)?)?;
     m.add_function(wrap_pyfunction!(extract_all, m)?)?;
+    m.add_function(wrap_pyfunction!(extract_matches, m)?)?;
     Ok(())
 }
@@ -0,0 +1,286 @@
+//! Business days ("dias úteis"): every day that is neither part of the weekend
+//! nor a holiday.
+
+use std::{collections::BTreeSet, sync::Arc};
+
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+
+use crate::{HolidayCalendar, Ordinal, ResolveError};
+
+/// Which days are business days. By default, every day but Saturday and Sunday.
+#[derive(Clone, Debug)]
+pub struct BusinessCalendar {
+    pub weekend: Vec<Weekday>,
+    pub holidays: Arc<dyn HolidayCalendar>,
+}
+
+impl Default for BusinessCalendar {
+    fn default() -> Self {
+        BusinessCalendar {
+            weekend: vec![Weekday::Sat, Weekday::Sun],
+            holidays: Arc::new(BTreeSet::new()),
+        }
+    }
+}
+
+impl BusinessCalendar {
+    pub fn is_business_day(&self, date: &NaiveDate) -> bool {
+        !self.weekend.contains(&date.weekday()) && !self.holidays.is_holiday(date)
+    }
+
+    /// The date `n` business days after `date`, or before it if `n` is negative.
+    /// `date` itself doesn't need to be a business day.
+    pub fn add_business_days(&self, date: &NaiveDate, n: i64) -> Result<NaiveDate, ResolveError> {
+        let per_week = (0..7)
+            .filter_map(|day| Weekday::try_from(day).ok())
+            .filter(|weekday| !self.weekend.contains(weekday))
+            .count() as u64;
+        if per_week == 0 {
+            return Err(ResolveError::NonexistentDate);
+        }
+        let mut remaining = n.unsigned_abs();
+        if remaining > (NaiveDate::MAX - NaiveDate::MIN).num_days().unsigned_abs() {
+            return Err(ResolveError::Overflow);
+        }
+        let mut date = *date;
+        // Skip whole weeks, which have `per_week` business days but for the
+        // holidays among them, then walk what is left day by day. At least one
+        // day is left to walk, so that a start on a weekend still ends on a
+        // business day.
+        while remaining > per_week {
+            let skipped = (remaining - 1) / per_week;
+            let weeks = Days::new(skipped * 7);
+            let (next, first, last) = match n {
+                ..0 => {
+                    let next = date.checked_sub_days(weeks);
+                    (next, next, date.pred_opt())
+                }
+                _ => {
+                    let next = date.checked_add_days(weeks);
+                    (next, date.succ_opt(), next)
+                }
+            };
+            let (Some(next), Some(first), Some(last)) = (next, first, last) else {
+                return Err(ResolveError::Overflow);
+            };
+            let holidays = self
+                .holidays
+                .holidays_between(&first, &last)
+                .iter()
+                .filter(|holiday| !self.weekend.contains(&holiday.weekday()))
+                .count() as u64;
+            remaining = remaining - skipped * per_week + holidays;
+            date = next;
+        }
+        for _ in 0..remaining {
+            loop {
+                date = match n {
+                    ..0 => date.pred_opt(),
+                    _ => date.succ_opt(),
+                }
+                .ok_or(ResolveError::Overflow)?;
+                if self.is_business_day(&date) {
+                    break;
+                }
+            }
+        }
+        Ok(date)
+    }
+
+    /// The first and last days of the weekend `date` is in, or else of the next
+    /// one. The weekend is the run of weekend days that follows a business day,
+    /// so a Friday and Saturday weekend works as well as the usual one. `None` if
+    /// no day, or every day, is part of the weekend.
+    pub(crate) fn weekend_of(&self, date: &NaiveDate) -> Option<(NaiveDate, NaiveDate)> {
+        let first = (0..7)
+            .filter_map(|day| Weekday::try_from(day).ok())
+            .find(|weekday| {
+                self.weekend.contains(weekday) && !self.weekend.contains(&weekday.pred())
+            })?;
+        let length = std::iter::successors(Some(first), |weekday| Some(weekday.succ()))
+            .take_while(|weekday| self.weekend.contains(weekday))
+            .count() as u64;
+        let days_since = u64::from(date.weekday().days_since(first));
+        let start = if days_since < length {
+            date.checked_sub_days(Days::new(days_since))?
+        } else {
+            date.checked_add_days(Days::new(7 - days_since))?
+        };
+        Some((start, start.checked_add_days(Days::new(length - 1))?))
+    }
+
+    /// This occurrence of a business day in the month of `date`, like the fifth
+    /// or the last one.
+    pub(crate) fn business_day_of_month(
+        &self,
+        date: &NaiveDate,
+        ordinal: &Ordinal,
+    ) -> Option<NaiveDate> {
+        let business_days: Vec<NaiveDate> = date
+            .with_day(1)?
+            .iter_days()
+            .take_while(|day| day.month() == date.month())
+            .filter(|day| self.is_business_day(day))
+            .collect();
+        let index = match ordinal {
+            Ordinal::First => 0,
+            Ordinal::Second => 1,
+            Ordinal::Third => 2,
+            Ordinal::Fourth => 3,
+            Ordinal::Fifth => 4,
+            Ordinal::Last => business_days.len().checked_sub(1)?,
+            Ordinal::SecondToLast => business_days.len().checked_sub(2)?,
+        };
+        business_days.get(index).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeSet, sync::Arc};
+
+    use chrono::{NaiveDate, Weekday};
+
+    use super::BusinessCalendar;
+    use crate::{BrazilianHolidays, Ordinal, ResolveError};
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_add_business_days() {
+        let calendar = BusinessCalendar::default();
+        // Thursday
+        let now = date(8, 15);
+        assert_eq!(calendar.add_business_days(&now, 1), Ok(date(8, 16)));
+        assert_eq!(calendar.add_business_days(&now, 2), Ok(date(8, 19)));
+        assert_eq!(calendar.add_business_days(&now, 5), Ok(date(8, 22)));
+        assert_eq!(calendar.add_business_days(&now, -4), Ok(date(8, 9)));
+        assert_eq!(calendar.add_business_days(&date(8, 17), 1), Ok(date(8, 19)));
+        assert_eq!(calendar.add_business_days(&now, 0), Ok(now));
+        // From a weekend, whole weeks still end on a business day.
+        assert_eq!(calendar.add_business_days(&date(8, 17), 5), Ok(date(8, 23)));
+        assert_eq!(
+            calendar.add_business_days(&date(8, 17), 10),
+            Ok(date(8, 30))
+        );
+        assert_eq!(calendar.add_business_days(&date(8, 18), 5), Ok(date(8, 23)));
+        assert_eq!(
+            calendar.add_business_days(&date(8, 18), -5),
+            Ok(date(8, 12))
+        );
+        assert_eq!(
+            calendar.add_business_days(&date(8, 17), -5),
+            Ok(date(8, 12))
+        );
+
+        let calendar = BusinessCalendar {
+            holidays: Arc::new(BTreeSet::from([date(8, 16), date(8, 19)])),
+            ..Default::default()
+        };
+        assert_eq!(calendar.add_business_days(&now, 1), Ok(date(8, 20)));
+
+        let calendar = BusinessCalendar {
+            weekend: vec![Weekday::Fri, Weekday::Sat],
+            ..Default::default()
+        };
+        assert_eq!(calendar.add_business_days(&now, 1), Ok(date(8, 18)));
+
+        let calendar = BusinessCalendar {
+            weekend: vec![
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+                Weekday::Sat,
+                Weekday::Sun,
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            calendar.add_business_days(&now, 1),
+            Err(ResolveError::NonexistentDate)
+        );
+    }
+
+    #[test]
+    fn test_add_business_days_skips_weeks() {
+        let calendar = BusinessCalendar {
+            holidays: Arc::new((BrazilianHolidays, BTreeSet::from([date(12, 24)]))),
+            ..Default::default()
+        };
+        // Walks day by day, as a reference for the week skipping.
+        let walk = |mut date: NaiveDate, n: i64| {
+            for _ in 0..n.unsigned_abs() {
+                date = match n {
+                    ..0 => date.pred_opt().unwrap(),
+                    _ => date.succ_opt().unwrap(),
+                };
+                while !calendar.is_business_day(&date) {
+                    date = match n {
+                        ..0 => date.pred_opt().unwrap(),
+                        _ => date.succ_opt().unwrap(),
+                    };
+                }
+            }
+            date
+        };
+        // Thursday, Saturday and Sunday
+        for start in [date(8, 15), date(8, 17), date(8, 18)] {
+            for n in [4, 5, 6, 10, 23, 100, 365, 1000, -5, -10, -37, -1000] {
+                assert_eq!(
+                    calendar.add_business_days(&start, n),
+                    Ok(walk(start, n)),
+                    "{} {}",
+                    start,
+                    n
+                );
+            }
+        }
+
+        assert_eq!(
+            calendar.add_business_days(&date(8, 15), 999_999_999_999),
+            Err(ResolveError::Overflow)
+        );
+        assert_eq!(
+            calendar.add_business_days(&date(8, 15), 100_000_000),
+            Err(ResolveError::Overflow)
+        );
+        assert_eq!(
+            calendar.add_business_days(&date(8, 15), i64::MIN),
+            Err(ResolveError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_business_day_of_month() {
+        let calendar = BusinessCalendar {
+            holidays: Arc::new(BTreeSet::from([date(11, 15)])),
+            ..Default::default()
+        };
+        let now = date(11, 20);
+        assert_eq!(
+            calendar.business_day_of_month(&now, &Ordinal::First),
+            Some(date(11, 1))
+        );
+        assert_eq!(
+            calendar.business_day_of_month(&now, &Ordinal::Fifth),
+            Some(date(11, 7))
+        );
+        // The 29th is a Friday and the 30th a Saturday.
+        assert_eq!(
+            calendar.business_day_of_month(&now, &Ordinal::Last),
+            Some(date(11, 29))
+        );
+        assert_eq!(
+            calendar.business_day_of_month(&now, &Ordinal::SecondToLast),
+            Some(date(11, 28))
+        );
+        assert_eq!(
+            calendar.add_business_days(&date(11, 14), 1),
+            Ok(date(11, 18))
+        );
+    }
+}
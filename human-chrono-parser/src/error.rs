@@ -0,0 +1,400 @@
+use std::fmt;
+
+// winnow 0.6.25 deprecates `ErrorKind`, but `ParserError` and
+// `FromExternalError` still take it until 0.7.
+#[allow(deprecated)]
+use winnow::error::ErrorKind;
+use winnow::{
+    error::{AddContext, FromExternalError, ParserError, StrContext, StrContextValue},
+    stream::{Offset, Stream},
+};
+
+use crate::{locales::Locale, normalize::Folded};
+
+/// Why [`crate::parse`] rejected its input. Offsets are in bytes into the input.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseError {
+    Empty,
+    /// Nothing the locale understands starts at `offset`.
+    Unrecognized {
+        offset: usize,
+        expected: Vec<Expected>,
+        suggestion: Option<Suggestion>,
+    },
+    /// An expression was read up to `offset`, but the input goes on with text
+    /// that is not part of it.
+    TrailingInput {
+        offset: usize,
+        expected: Vec<Expected>,
+        suggestion: Option<Suggestion>,
+    },
+}
+
+/// Why an expression could not be resolved to a date.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ResolveError {
+    /// The date falls outside the range chrono can represent.
+    Overflow,
+    /// The expression names a date that doesn't exist, like February 30 or the
+    /// fifth Sunday of a month with four.
+    NonexistentDate,
+    /// The expression doesn't resolve to a single date.
+    Unsupported,
+    /// The end of a range falls before its start.
+    InvertedRange,
+}
+
+/// A kind of construct the parser was looking for.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Expected {
+    Keyword,
+    Number,
+    Weekday,
+    Month,
+    /// A unit of time, like "dias".
+    Unit,
+    Time,
+    End,
+}
+
+/// A word of the locale vocabulary close to a misspelled word of the input,
+/// which spans `start..end`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Suggestion {
+    pub start: usize,
+    pub end: usize,
+    pub replacement: &'static str,
+}
+
+const EXPRESSION_START: &[Expected] = &[
+    Expected::Keyword,
+    Expected::Number,
+    Expected::Weekday,
+    Expected::Month,
+    Expected::Time,
+];
+
+const AFTER_EXPRESSION: &[Expected] = &[Expected::Time, Expected::End];
+
+impl ParseError {
+    pub fn offset(&self) -> usize {
+        match self {
+            ParseError::Empty => 0,
+            ParseError::Unrecognized { offset, .. } | ParseError::TrailingInput { offset, .. } => {
+                *offset
+            }
+        }
+    }
+
+    pub fn expected(&self) -> &[Expected] {
+        match self {
+            ParseError::Empty => EXPRESSION_START,
+            ParseError::Unrecognized { expected, .. }
+            | ParseError::TrailingInput { expected, .. } => expected,
+        }
+    }
+
+    pub fn suggestion(&self) -> Option<&Suggestion> {
+        match self {
+            ParseError::Empty => None,
+            ParseError::Unrecognized { suggestion, .. }
+            | ParseError::TrailingInput { suggestion, .. } => suggestion.as_ref(),
+        }
+    }
+
+    /// Nothing could be read: `error` tells how far the grammar got, and what
+    /// it expected there.
+    pub(crate) fn unrecognized(
+        folded: &Folded,
+        error: GrammarError,
+        locale: &Locale,
+    ) -> ParseError {
+        let text = folded.as_str();
+        if text.is_empty() {
+            return ParseError::Empty;
+        }
+        let folded_offset = text.len() - error.remaining.min(text.len());
+        let expected = match (error.expected.is_empty(), folded_offset) {
+            (true, 0) => EXPRESSION_START.to_vec(),
+            _ => error.expected,
+        };
+        ParseError::Unrecognized {
+            offset: folded.source_offset(folded_offset),
+            expected,
+            suggestion: suggest(folded, folded_offset, locale),
+        }
+    }
+
+    /// An expression was read up to `folded_offset` in the folded input.
+    pub(crate) fn trailing(folded: &Folded, folded_offset: usize, locale: &Locale) -> ParseError {
+        ParseError::TrailingInput {
+            offset: folded.source_offset(folded_offset),
+            expected: AFTER_EXPRESSION.to_vec(),
+            suggestion: suggest(folded, folded_offset, locale),
+        }
+    }
+}
+
+/// The error of the locale grammars. Unlike winnow's `ContextError`, it keeps
+/// the furthest position any alternative reached, and what the parsers marked
+/// with a [`StrContext::Expected`] description expected there.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GrammarError {
+    /// How much input was left where the furthest alternative failed.
+    remaining: usize,
+    expected: Vec<Expected>,
+}
+
+impl GrammarError {
+    /// Maps the failure position in `folded` back to its source, which is
+    /// `source_len` bytes long.
+    pub(crate) fn unfold(mut self, folded: &Folded, source_len: usize) -> Self {
+        let len = folded.as_str().len();
+        self.remaining = source_len - folded.source_offset(len - self.remaining.min(len));
+        self
+    }
+}
+
+impl Expected {
+    const ALL: [Expected; 7] = [
+        Expected::Keyword,
+        Expected::Number,
+        Expected::Weekday,
+        Expected::Month,
+        Expected::Unit,
+        Expected::Time,
+        Expected::End,
+    ];
+
+    fn description(&self) -> &'static str {
+        match self {
+            Expected::Keyword => "keyword",
+            Expected::Number => "number",
+            Expected::Weekday => "weekday",
+            Expected::Month => "month",
+            Expected::Unit => "unit of time",
+            Expected::Time => "time",
+            Expected::End => "end of input",
+        }
+    }
+
+    /// The context that marks a grammar parser as reading this construct.
+    pub(crate) fn context(&self) -> StrContext {
+        StrContext::Expected(StrContextValue::Description(self.description()))
+    }
+}
+
+#[allow(deprecated)]
+impl<I: Stream> ParserError<I> for GrammarError {
+    fn from_error_kind(input: &I, _kind: ErrorKind) -> Self {
+        GrammarError {
+            remaining: input.eof_offset(),
+            expected: vec![],
+        }
+    }
+
+    fn append(
+        self,
+        _input: &I,
+        _token_start: &<I as Stream>::Checkpoint,
+        _kind: ErrorKind,
+    ) -> Self {
+        self
+    }
+
+    fn or(mut self, other: Self) -> Self {
+        match self.remaining.cmp(&other.remaining) {
+            std::cmp::Ordering::Less => self,
+            std::cmp::Ordering::Greater => other,
+            std::cmp::Ordering::Equal => {
+                for expected in other.expected {
+                    if !self.expected.contains(&expected) {
+                        self.expected.push(expected);
+                    }
+                }
+                self
+            }
+        }
+    }
+}
+
+impl<'a> AddContext<&'a str, StrContext> for GrammarError {
+    /// Only parsers that started where the furthest alternative failed tell
+    /// what was expected there.
+    fn add_context(
+        mut self,
+        input: &&'a str,
+        token_start: &<&'a str as Stream>::Checkpoint,
+        context: StrContext,
+    ) -> Self {
+        let started_at = input.eof_offset() + input.offset_from(token_start);
+        if let StrContext::Expected(StrContextValue::Description(description)) = context {
+            let expected = Expected::ALL
+                .into_iter()
+                .find(|expected| expected.description() == description);
+            if let Some(expected) = expected {
+                if started_at == self.remaining && !self.expected.contains(&expected) {
+                    self.expected.push(expected);
+                }
+            }
+        }
+        self
+    }
+}
+
+#[allow(deprecated)]
+impl<I, E> FromExternalError<I, E> for GrammarError
+where
+    I: Stream,
+{
+    fn from_external_error(input: &I, kind: ErrorKind, _e: E) -> Self {
+        Self::from_error_kind(input, kind)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => return write!(f, "empty input"),
+            ParseError::Unrecognized { offset, .. } => {
+                write!(f, "unrecognized date expression at offset {}", offset)?
+            }
+            ParseError::TrailingInput { offset, .. } => {
+                write!(f, "unexpected text at offset {}", offset)?
+            }
+        }
+        let expected = self.expected();
+        for (i, construct) in expected.iter().enumerate() {
+            let separator = match i {
+                0 => ", expected ",
+                _ if i == expected.len() - 1 => " or ",
+                _ => ", ",
+            };
+            write!(f, "{}{}", separator, construct)?;
+        }
+        if let Some(suggestion) = self.suggestion() {
+            write!(f, "; did you mean \"{}\"?", suggestion.replacement)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ResolveError::Overflow => "date out of range",
+            ResolveError::NonexistentDate => "nonexistent date",
+            ResolveError::Unsupported => "expression does not resolve to a single date",
+            ResolveError::InvertedRange => "range ends before it starts",
+        })
+    }
+}
+
+impl std::error::Error for ResolveError {}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+/// Looks at the first word ending after `folded_offset` that is not part of the
+/// locale vocabulary, and suggests the closest vocabulary word if it is only a
+/// typo or two away.
+fn suggest(folded: &Folded, folded_offset: usize, locale: &Locale) -> Option<Suggestion> {
+    let vocabulary: Vec<(&'static str, Folded)> = locale
+        .vocabulary()
+        .into_iter()
+        .map(|word| (word, Folded::new(word)))
+        .collect();
+    let text = folded.as_str();
+    let (start, word) = words(text)
+        .filter(|(start, word)| start + word.len() > folded_offset)
+        .find(|(_, word)| {
+            !word.starts_with(|c: char| c.is_ascii_digit())
+                && !vocabulary.iter().any(|(_, known)| known.as_str() == *word)
+        })?;
+
+    let max_distance = match word.chars().count() {
+        0..=3 => return None,
+        4..=5 => 1,
+        _ => 2,
+    };
+    let (distance, _, replacement) = vocabulary
+        .iter()
+        .map(|(replacement, known)| {
+            let known = known.as_str();
+            let distance = levenshtein(word, known);
+            (distance, word.len().abs_diff(known.len()), *replacement)
+        })
+        .min_by_key(|(distance, length_difference, _)| (*distance, *length_difference))?;
+    (distance <= max_distance).then(|| Suggestion {
+        start: folded.source_offset(start),
+        end: folded.source_offset(start + word.len()),
+        replacement,
+    })
+}
+
+fn words(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(move |word| (word.offset_from(&text), word))
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{levenshtein, Expected, ParseError, Suggestion};
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("proxma", "proxima"), 1);
+        assert_eq!(levenshtein("amanah", "amanha"), 2);
+        assert_eq!(levenshtein("", "hoje"), 4);
+        assert_eq!(levenshtein("hoje", "hoje"), 0);
+    }
+
+    #[test]
+    fn test_display() {
+        let err = ParseError::Unrecognized {
+            offset: 0,
+            expected: vec![Expected::Keyword, Expected::Weekday, Expected::Time],
+            suggestion: Some(Suggestion {
+                start: 0,
+                end: 6,
+                replacement: "próxima",
+            }),
+        };
+        assert_eq!(
+            err.to_string(),
+            "unrecognized date expression at offset 0, expected keyword, weekday or time; \
+             did you mean \"próxima\"?"
+        );
+
+        let err = ParseError::TrailingInput {
+            offset: 6,
+            expected: vec![Expected::Time, Expected::End],
+            suggestion: None,
+        };
+        assert_eq!(
+            err.to_string(),
+            "unexpected text at offset 6, expected time or end of input"
+        );
+    }
+}
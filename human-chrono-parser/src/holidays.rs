@@ -0,0 +1,290 @@
+//! Holidays computed offline from their rules: fixed dates, and movable feasts
+//! counted from Easter.
+
+use std::{cell::Cell, collections::BTreeSet, fmt};
+
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+
+/// A holiday or commemorative date with a rule to find its date in any year.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Holiday {
+    /// January 1.
+    NewYear,
+    /// The Monday before Ash Wednesday.
+    CarnivalMonday,
+    /// Shrove Tuesday, 47 days before Easter.
+    Carnival,
+    /// The Friday before Easter.
+    GoodFriday,
+    Easter,
+    /// April 21.
+    Tiradentes,
+    /// May 1.
+    LabourDay,
+    /// 60 days after Easter.
+    CorpusChristi,
+    /// September 7.
+    Independence,
+    /// October 12.
+    OurLadyOfAparecida,
+    /// November 2.
+    AllSouls,
+    /// November 15.
+    Republic,
+    /// November 20, a national holiday since 2024.
+    BlackConsciousness,
+    /// December 25.
+    Christmas,
+    /// The second Sunday of May, as in Brazil and the United States.
+    MothersDay,
+    /// The second Sunday of August, as in Brazil.
+    FathersDay,
+    /// The day after the fourth Thursday of November.
+    BlackFriday,
+}
+
+impl Holiday {
+    /// The date of the holiday in `year`, if it is observed that year.
+    pub fn date(&self, year: i32) -> Option<NaiveDate> {
+        let fixed = |month, day| NaiveDate::from_ymd_opt(year, month, day);
+        let from_easter = |days: i64| {
+            let easter = easter(year)?;
+            match days {
+                ..0 => easter.checked_sub_days(Days::new(days.unsigned_abs())),
+                _ => easter.checked_add_days(Days::new(days.unsigned_abs())),
+            }
+        };
+        match self {
+            Holiday::NewYear => fixed(1, 1),
+            Holiday::CarnivalMonday => from_easter(-48),
+            Holiday::Carnival => from_easter(-47),
+            Holiday::GoodFriday => from_easter(-2),
+            Holiday::Easter => from_easter(0),
+            Holiday::Tiradentes => fixed(4, 21),
+            Holiday::LabourDay => fixed(5, 1),
+            Holiday::CorpusChristi => from_easter(60),
+            Holiday::Independence => fixed(9, 7),
+            Holiday::OurLadyOfAparecida => fixed(10, 12),
+            Holiday::AllSouls => fixed(11, 2),
+            Holiday::Republic => fixed(11, 15),
+            Holiday::BlackConsciousness if year >= 2024 => fixed(11, 20),
+            Holiday::BlackConsciousness => None,
+            Holiday::Christmas => fixed(12, 25),
+            Holiday::MothersDay => NaiveDate::from_weekday_of_month_opt(year, 5, Weekday::Sun, 2),
+            Holiday::FathersDay => NaiveDate::from_weekday_of_month_opt(year, 8, Weekday::Sun, 2),
+            Holiday::BlackFriday => {
+                NaiveDate::from_weekday_of_month_opt(year, 11, Weekday::Thu, 4)?.succ_opt()
+            }
+        }
+    }
+}
+
+/// Easter Sunday of `year` in the Gregorian calendar, by the anonymous
+/// Gregorian algorithm.
+pub fn easter(year: i32) -> Option<NaiveDate> {
+    let a = year.rem_euclid(19);
+    let (b, c) = (year.div_euclid(100), year.rem_euclid(100));
+    let (d, e) = (b / 4, b % 4);
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15).rem_euclid(30);
+    let (i, k) = (c / 4, c % 4);
+    let l = (32 + 2 * e + 2 * i - h - k).rem_euclid(7);
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month.try_into().ok()?, day.try_into().ok()?)
+}
+
+/// The days off of a country, region or company.
+pub trait HolidayCalendar: fmt::Debug + Send + Sync {
+    fn is_holiday(&self, date: &NaiveDate) -> bool;
+
+    /// The date of `holiday` in `year`. Calendars only need to override this
+    /// when they observe a holiday on another day than its rule gives.
+    fn date_of(&self, holiday: &Holiday, year: i32) -> Option<NaiveDate> {
+        holiday.date(year)
+    }
+
+    /// The holidays from `start` to `end`, both included, in order. Business
+    /// day arithmetic uses it to skip whole weeks; the default checks every day,
+    /// so calendars that know their dates should override it.
+    fn holidays_between(&self, start: &NaiveDate, end: &NaiveDate) -> Vec<NaiveDate> {
+        start
+            .iter_days()
+            .take_while(|date| date <= end)
+            .filter(|date| self.is_holiday(date))
+            .collect()
+    }
+}
+
+/// Brazilian national holidays, with Carnival Monday and Tuesday.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BrazilianHolidays;
+
+impl BrazilianHolidays {
+    pub const HOLIDAYS: &'static [Holiday] = &[
+        Holiday::NewYear,
+        Holiday::CarnivalMonday,
+        Holiday::Carnival,
+        Holiday::GoodFriday,
+        Holiday::Tiradentes,
+        Holiday::LabourDay,
+        Holiday::CorpusChristi,
+        Holiday::Independence,
+        Holiday::OurLadyOfAparecida,
+        Holiday::AllSouls,
+        Holiday::Republic,
+        Holiday::BlackConsciousness,
+        Holiday::Christmas,
+    ];
+}
+
+type YearHolidays = [Option<NaiveDate>; BrazilianHolidays::HOLIDAYS.len()];
+
+thread_local! {
+    /// The dates of the last year looked up, as business day arithmetic checks
+    /// many days of the same year in a row.
+    static LAST_YEAR: Cell<Option<(i32, YearHolidays)>> = const { Cell::new(None) };
+}
+
+impl BrazilianHolidays {
+    fn dates(year: i32) -> YearHolidays {
+        LAST_YEAR.with(|last| match last.get() {
+            Some((last_year, dates)) if last_year == year => dates,
+            _ => {
+                let mut dates = [None; Self::HOLIDAYS.len()];
+                for (date, holiday) in dates.iter_mut().zip(Self::HOLIDAYS) {
+                    *date = holiday.date(year);
+                }
+                last.set(Some((year, dates)));
+                dates
+            }
+        })
+    }
+}
+
+impl HolidayCalendar for BrazilianHolidays {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        Self::dates(date.year()).contains(&Some(*date))
+    }
+
+    fn holidays_between(&self, start: &NaiveDate, end: &NaiveDate) -> Vec<NaiveDate> {
+        let mut holidays: Vec<NaiveDate> = (start.year()..=end.year())
+            .flat_map(Self::dates)
+            .flatten()
+            .filter(|date| start <= date && date <= end)
+            .collect();
+        holidays.sort();
+        holidays.dedup();
+        holidays
+    }
+}
+
+/// A plain set of dates, for days off that follow no rule.
+impl HolidayCalendar for BTreeSet<NaiveDate> {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        self.contains(date)
+    }
+
+    fn holidays_between(&self, start: &NaiveDate, end: &NaiveDate) -> Vec<NaiveDate> {
+        match start <= end {
+            true => self.range(start..=end).copied().collect(),
+            false => vec![],
+        }
+    }
+}
+
+/// Both calendars' holidays, like national holidays and a company's own days
+/// off. Holiday dates come from the first calendar.
+impl<A: HolidayCalendar, B: HolidayCalendar> HolidayCalendar for (A, B) {
+    fn is_holiday(&self, date: &NaiveDate) -> bool {
+        self.0.is_holiday(date) || self.1.is_holiday(date)
+    }
+
+    fn date_of(&self, holiday: &Holiday, year: i32) -> Option<NaiveDate> {
+        self.0.date_of(holiday, year)
+    }
+
+    fn holidays_between(&self, start: &NaiveDate, end: &NaiveDate) -> Vec<NaiveDate> {
+        let mut holidays = self.0.holidays_between(start, end);
+        holidays.extend(self.1.holidays_between(start, end));
+        holidays.sort();
+        holidays.dedup();
+        holidays
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use chrono::NaiveDate;
+
+    use super::{easter, BrazilianHolidays, Holiday, HolidayCalendar};
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_easter() {
+        assert_eq!(easter(2000), Some(date(2000, 4, 23)));
+        assert_eq!(easter(2019), Some(date(2019, 4, 21)));
+        assert_eq!(easter(2024), Some(date(2024, 3, 31)));
+        assert_eq!(easter(2025), Some(date(2025, 4, 20)));
+        assert_eq!(easter(2026), Some(date(2026, 4, 5)));
+        assert_eq!(easter(2038), Some(date(2038, 4, 25)));
+    }
+
+    #[test]
+    fn test_movable_holidays() {
+        assert_eq!(Holiday::CarnivalMonday.date(2024), Some(date(2024, 2, 12)));
+        assert_eq!(Holiday::Carnival.date(2024), Some(date(2024, 2, 13)));
+        assert_eq!(Holiday::GoodFriday.date(2024), Some(date(2024, 3, 29)));
+        assert_eq!(Holiday::CorpusChristi.date(2024), Some(date(2024, 5, 30)));
+        assert_eq!(Holiday::Carnival.date(2025), Some(date(2025, 3, 4)));
+        assert_eq!(Holiday::CorpusChristi.date(2025), Some(date(2025, 6, 19)));
+        assert_eq!(Holiday::MothersDay.date(2025), Some(date(2025, 5, 11)));
+        assert_eq!(Holiday::FathersDay.date(2025), Some(date(2025, 8, 10)));
+        assert_eq!(Holiday::BlackFriday.date(2024), Some(date(2024, 11, 29)));
+        assert_eq!(Holiday::BlackFriday.date(2025), Some(date(2025, 11, 28)));
+    }
+
+    #[test]
+    fn test_brazilian_holidays() {
+        let calendar = BrazilianHolidays;
+        assert!(calendar.is_holiday(&date(2024, 4, 21)));
+        assert!(calendar.is_holiday(&date(2024, 9, 7)));
+        assert!(calendar.is_holiday(&date(2024, 11, 2)));
+        assert!(calendar.is_holiday(&date(2025, 3, 3)));
+        assert!(calendar.is_holiday(&date(2025, 4, 18)));
+        assert!(calendar.is_holiday(&date(2024, 11, 20)));
+        assert!(!calendar.is_holiday(&date(2023, 11, 20)));
+        assert!(!calendar.is_holiday(&date(2024, 11, 21)));
+        assert!(!calendar.is_holiday(&date(2024, 3, 31)));
+
+        assert_eq!(
+            calendar.holidays_between(&date(2024, 11, 1), &date(2025, 1, 1)),
+            vec![
+                date(2024, 11, 2),
+                date(2024, 11, 15),
+                date(2024, 11, 20),
+                date(2024, 12, 25),
+                date(2025, 1, 1)
+            ]
+        );
+
+        let calendar = (BrazilianHolidays, BTreeSet::from([date(2024, 12, 24)]));
+        assert_eq!(
+            calendar.holidays_between(&date(2024, 12, 1), &date(2024, 12, 31)),
+            vec![date(2024, 12, 24), date(2024, 12, 25)]
+        );
+        assert!(calendar.is_holiday(&date(2024, 12, 24)));
+        assert!(calendar.is_holiday(&date(2024, 12, 25)));
+        assert_eq!(
+            calendar.date_of(&Holiday::Christmas, 2025),
+            Some(date(2025, 12, 25))
+        );
+    }
+}
@@ -1,85 +1,1033 @@
-use chrono::{Datelike, Days, Month, NaiveDate, Weekday};
+use chrono::{
+    DateTime, Datelike, Days, FixedOffset, LocalResult, Month, Months, NaiveDate, NaiveDateTime,
+    NaiveTime, TimeDelta, TimeZone, Utc, Weekday,
+};
 use winnow::{
-    combinator::{repeat, repeat_till},
-    error::{ContextError, ParseError},
+    combinator::{alt, repeat, repeat_till},
+    error::ErrMode,
+    stream::Offset,
     token::any,
     Parser,
 };
 
+mod business;
+mod error;
+mod holidays;
 pub mod locales;
+mod normalize;
+
+pub use business::BusinessCalendar;
+pub use error::{Expected, GrammarError, ParseError, ResolveError, Suggestion};
+pub use holidays::{easter, BrazilianHolidays, Holiday, HolidayCalendar};
+
+#[cfg(feature = "chrono-tz")]
+pub use chrono_tz;
+
+use locales::{Locale, PResult};
+use normalize::Folded;
 
-use locales::Locale;
+pub fn parse(input: &mut &str, locale: &Locale) -> Result<HumanDateExpr, ParseError> {
+    let folded = Folded::new(input);
+    let text = folded.as_str();
+    let mut parser = locale.grammar();
+    match parser.parse_peek(text) {
+        Ok(("", expr)) => Ok(expr),
+        Ok((rest, _)) => Err(ParseError::trailing(
+            &folded,
+            rest.offset_from(&text),
+            locale,
+        )),
+        Err(err) => Err(ParseError::unrecognized(
+            &folded,
+            err.into_inner().unwrap_or_default(),
+            locale,
+        )),
+    }
+}
+
+pub fn extract_all<'a>(input: &mut &'a str, locale: &'a Locale) -> Vec<HumanDateExpr> {
+    extract_all_with(input, locale, &ExtractOptions::default())
+}
 
-pub fn parse<'a>(
+pub fn extract_all_with<'a>(
     input: &mut &'a str,
     locale: &'a Locale,
-) -> Result<HumanDateExpr, ParseError<&'a str, ContextError>> {
-    let mut parser = locale.parser();
-    Ok(parser.parse(input)?)
+    options: &ExtractOptions,
+) -> Vec<HumanDateExpr> {
+    extract_matches_with(input, locale, options)
+        .into_iter()
+        .map(|m| m.expr)
+        .collect()
 }
 
-pub fn extract_all<'a>(input: &mut &'a str, locale: &'a Locale) -> Vec<HumanDateExpr> {
-    match repeat(
+/// Like [`extract_all`], but also reports where each expression was found.
+pub fn extract_matches<'a>(input: &mut &'a str, locale: &'a Locale) -> Vec<Match<'a>> {
+    extract_matches_with(input, locale, &ExtractOptions::default())
+}
+
+pub fn extract_matches_with<'a>(
+    input: &mut &'a str,
+    locale: &'a Locale,
+    options: &ExtractOptions,
+) -> Vec<Match<'a>> {
+    let folded = Folded::new(input);
+    let haystack = &mut folded.as_str();
+    let found: Vec<(HumanDateExpr, &str)> = repeat(
         0..,
-        repeat_till::<_, (), Vec<()>, HumanDateExpr, _, _, _>(.., any.void(), locale.parser())
-            .map(|(_, expr)| expr),
+        repeat_till::<_, (), Vec<()>, (HumanDateExpr, &str), _, _, _>(
+            ..,
+            alt((
+                locale
+                    .non_dates()
+                    .with_taken()
+                    .verify(|(_, text): &((), &str)| {
+                        !options.word_boundaries || on_word_boundaries(folded.as_str(), text)
+                    })
+                    .void(),
+                any.void(),
+            )),
+            |input: &mut _| match options.word_boundaries {
+                true => parse_on_word_boundaries(folded.as_str(), locale, input),
+                false => locale.grammar().with_taken().parse_next(input),
+            },
+        )
+        .map(|(_, found)| found),
     )
-    .parse_next(input)
+    .parse_next(haystack)
+    .unwrap_or_default();
+    let source = *input;
+    *input = &source[folded.source_offset(haystack.offset_from(&folded.as_str()))..];
+
+    // Matches come in order, so char offsets are counted incrementally from the
+    // previous match instead of from the start of the input each time.
+    let (mut byte_pos, mut char_pos) = (0, 0);
+    found
+        .into_iter()
+        .map(|(expr, found)| {
+            let folded_start = found.offset_from(&folded.as_str());
+            let start = folded.source_offset(folded_start);
+            let end = folded.source_offset(folded_start + found.len());
+            let text = &source[start..end];
+            let char_start = char_pos + source[byte_pos..start].chars().count();
+            let char_end = char_start + text.chars().count();
+            (byte_pos, char_pos) = (end, char_end);
+            Match {
+                expr,
+                start,
+                end,
+                char_start,
+                char_end,
+                text,
+            }
+        })
+        .collect()
+}
+
+#[derive(Clone, Debug)]
+pub struct ExtractOptions {
+    /// Only accept matches that are whole words, so "seg" is not found inside
+    /// "segurança" nor "ter" inside "interno". Enabled by default.
+    pub word_boundaries: bool,
+}
+
+impl Default for ExtractOptions {
+    fn default() -> Self {
+        ExtractOptions {
+            word_boundaries: true,
+        }
+    }
+}
+
+/// Whether `text`, a slice of `source`, is neither preceded nor followed by a
+/// letter or digit.
+fn on_word_boundaries(source: &str, text: &str) -> bool {
+    let start = text.offset_from(&source);
+    let end = start + text.len();
+    let is_word_char = |c: char| c.is_alphanumeric();
+    !source[..start]
+        .chars()
+        .next_back()
+        .is_some_and(is_word_char)
+        && !source[end..].chars().next().is_some_and(is_word_char)
+}
+
+/// Parses an expression at the start of `input`, a suffix of `source`, whose
+/// text is on word boundaries. When the longest reading is not, like "amanhã às
+/// 10h" in "amanhã às 10hs", shorter ones ending at an earlier word end are
+/// tried, down to "amanhã".
+fn parse_on_word_boundaries<'i>(
+    source: &'i str,
+    locale: &'i Locale,
+    input: &mut &'i str,
+) -> PResult<(HumanDateExpr, &'i str)> {
+    let is_word_char = |c: char| c.is_alphanumeric();
+    let start = *input;
+    let backtrack = || ErrMode::Backtrack(GrammarError::default());
+    if source[..start.offset_from(&source)]
+        .chars()
+        .next_back()
+        .is_some_and(is_word_char)
     {
-        Ok(result) => result,
-        Err(_) => vec![],
+        return Err(backtrack());
+    }
+    let mut end = start.len();
+    loop {
+        let (expr, text) = locale
+            .grammar()
+            .with_taken()
+            .parse_next(&mut &start[..end])?;
+        if on_word_boundaries(source, text) {
+            *input = &start[text.len()..];
+            return Ok((expr, text));
+        }
+        end = text
+            .char_indices()
+            .rev()
+            .find(|&(i, c)| {
+                !is_word_char(c) && text[..i].chars().next_back().is_some_and(is_word_char)
+            })
+            .map(|(i, _)| i)
+            .ok_or_else(backtrack)?;
     }
 }
 
+/// An expression found by [`extract_matches`] and its location in the input.
+///
+/// `start` and `end` are byte offsets, usable to slice the input; `char_start` and
+/// `char_end` count Unicode scalar values, for consumers that index by character.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Match<'a> {
+    pub expr: HumanDateExpr,
+    pub start: usize,
+    pub end: usize,
+    pub char_start: usize,
+    pub char_end: usize,
+    pub text: &'a str,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum HumanDateKeyword {
     Today,
     Tomorrow,
     AfterTomorrow,
+    Yesterday,
+    BeforeYesterday,
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum HumanDateExpr {
     Keyword(HumanDateKeyword),
+    #[deprecated(note = "parsers produce `InN(n, TimeUnit::Day)` instead")]
     InNDays(u64),
+    InN(u64, TimeUnit),
+    NAgo(u64, TimeUnit),
     ThisWeekWeekday(Weekday),
     NextWeekWeekday(Weekday),
-    OrdinalWeekdayOfMonth(Ordinal, Weekday, Month),
+    /// A weekday of the calendar week after the reference date's, like "segunda
+    /// da próxima semana", whatever the week mode. Weeks start on
+    /// [`ResolveOptions::week_start`].
+    WeekdayOfNextWeek(Weekday),
+    OrdinalWeekdayOfMonth(Ordinal, Weekday, Month, Option<Year>),
+    /// Like `OrdinalWeekdayOfMonth`, in the month `n` months after the reference
+    /// date's, like "primeira segunda do mês" (`0`) or "última sexta do mês que vem" (`1`).
+    OrdinalWeekdayOfRelativeMonth(Ordinal, Weekday, i32),
+    /// A calendar date. Missing month and year are taken from the reference date.
+    Absolute {
+        day: u32,
+        month: Option<Month>,
+        year: Option<i32>,
+    },
+    /// A time of day on the reference date, like "às 15h".
+    Time(HumanTime),
+    /// A date expression with a time of day, like "amanhã às 10h".
+    DateTime(Box<HumanDateExpr>, HumanTime),
+    /// `n` business days after the reference date, or before it if negative,
+    /// like "em 5 dias úteis" or "próximo dia útil".
+    BusinessDays(i64),
+    /// A business day of the month `n` months after the reference date's, like
+    /// "quinto dia útil do mês" or "último dia útil do mês que vem".
+    BusinessDayOfRelativeMonth(Ordinal, i32),
+    /// A holiday or commemorative date, like "Natal" or "Dia das Mães", in the
+    /// given year or, without one, in the year picked by the year policy.
+    Holiday(Holiday, Option<Year>),
+    /// Another expression shifted some time before or after, like "2 dias antes
+    /// do Natal" or "3 dias depois do dia 10 de maio". Offsets of ranges and
+    /// periods shift both ends, and offsets of a date and time keep the time.
+    Offset {
+        base: Box<HumanDateExpr>,
+        delta: (u64, TimeUnit),
+        direction: Direction,
+    },
+    /// The days from one date expression to another, like "de segunda a sexta".
+    Range(Box<HumanDateExpr>, Box<HumanDateExpr>),
+    /// The period `n` periods after the one the reference date is in, like
+    /// "esta semana" (`0`) or "mês que vem" (`1`).
+    RelativePeriod(Period, i32),
 }
 
 impl HumanDateExpr {
     pub fn relative_to(&self, now: &NaiveDate) -> Option<NaiveDate> {
+        self.try_relative_to(now).ok()
+    }
+
+    pub fn try_relative_to(&self, now: &NaiveDate) -> Result<NaiveDate, ResolveError> {
+        self.try_relative_to_with(now, &ResolveOptions::default())
+    }
+
+    pub fn try_relative_to_with(
+        &self,
+        now: &NaiveDate,
+        options: &ResolveOptions,
+    ) -> Result<NaiveDate, ResolveError> {
         match self {
             HumanDateExpr::Keyword(keyword) => match keyword {
-                HumanDateKeyword::Today => Some(now.clone()),
-                HumanDateKeyword::Tomorrow => Some(now.checked_add_days(Days::new(1)).unwrap()),
-                HumanDateKeyword::AfterTomorrow => {
-                    Some(now.checked_add_days(Days::new(2)).unwrap())
+                HumanDateKeyword::Today => Ok(*now),
+                HumanDateKeyword::Tomorrow => add_days(now, 1),
+                HumanDateKeyword::AfterTomorrow => add_days(now, 2),
+                HumanDateKeyword::Yesterday => TimeUnit::Day.sub_from(now, 1),
+                HumanDateKeyword::BeforeYesterday => TimeUnit::Day.sub_from(now, 2),
+            },
+            #[allow(deprecated)]
+            HumanDateExpr::InNDays(n) => TimeUnit::Day.add_to(now, *n),
+            HumanDateExpr::InN(n, unit) => unit.add_to(now, *n),
+            HumanDateExpr::NAgo(n, unit) => unit.sub_from(now, *n),
+            HumanDateExpr::ThisWeekWeekday(weekday) => match options.week_mode {
+                WeekMode::Rolling | WeekMode::NextOccurrence => {
+                    add_days(now, weekday.days_since(now.weekday()).into())
+                }
+                WeekMode::Calendar => options.weekday_of_week(now, weekday, 0),
+            },
+            HumanDateExpr::NextWeekWeekday(weekday) => match options.week_mode {
+                WeekMode::Rolling => add_days(now, (7 + weekday.days_since(now.weekday())).into()),
+                WeekMode::NextOccurrence => {
+                    add_days(now, (1 + weekday.days_since(now.weekday().succ())).into())
                 }
+                WeekMode::Calendar => options.weekday_of_week(now, weekday, 1),
             },
-            HumanDateExpr::InNDays(n) => Some(now.checked_add_days(Days::new(*n)).unwrap()),
-            HumanDateExpr::ThisWeekWeekday(weekday) => {
-                let n = (7 - now.weekday().number_from_sunday() + weekday.number_from_sunday()) % 7;
-                Some(now.checked_add_days(Days::new(n.into())).unwrap())
+            HumanDateExpr::WeekdayOfNextWeek(weekday) => options.weekday_of_week(now, weekday, 1),
+            HumanDateExpr::OrdinalWeekdayOfMonth(ordinal, weekday, month, year) => {
+                let candidate = |year: i32| ordinal.weekday_of_month(year, month, weekday);
+                match year {
+                    Some(year) => candidate(year.resolve(now).ok_or(ResolveError::Overflow)?)
+                        .ok_or(ResolveError::NonexistentDate),
+                    None => options
+                        .year_policy
+                        .pick(now, |offset| candidate(now.year() + offset)),
+                }
+            }
+            HumanDateExpr::Holiday(holiday, year) => {
+                let holidays = &options.business_calendar.holidays;
+                let candidate = |year: i32| holidays.date_of(holiday, year);
+                match year {
+                    Some(year) => candidate(year.resolve(now).ok_or(ResolveError::Overflow)?)
+                        .ok_or(ResolveError::NonexistentDate),
+                    None => options
+                        .year_policy
+                        .pick(now, |offset| candidate(now.year() + offset)),
+                }
+            }
+            HumanDateExpr::Offset {
+                base,
+                delta: (n, unit),
+                direction,
+            } => {
+                let base = base.try_relative_to_with(now, options)?;
+                direction.shift(&base, *n, unit)
+            }
+            HumanDateExpr::BusinessDays(n) => options.business_calendar.add_business_days(now, *n),
+            HumanDateExpr::BusinessDayOfRelativeMonth(ordinal, n) => {
+                let month = now
+                    .with_day(1)
+                    .and_then(|this_month| TimeUnit::Month.checked_shift(&this_month, *n))
+                    .ok_or(ResolveError::Overflow)?;
+                options
+                    .business_calendar
+                    .business_day_of_month(&month, ordinal)
+                    .ok_or(ResolveError::NonexistentDate)
+            }
+            HumanDateExpr::OrdinalWeekdayOfRelativeMonth(ordinal, weekday, n) => {
+                let month = now
+                    .with_day(1)
+                    .and_then(|this_month| TimeUnit::Month.checked_shift(&this_month, *n))
+                    .ok_or(ResolveError::Overflow)?;
+                let name = Month::try_from(month.month() as u8).or(Err(ResolveError::Overflow))?;
+                ordinal
+                    .weekday_of_month(month.year(), &name, weekday)
+                    .ok_or(ResolveError::NonexistentDate)
+            }
+            HumanDateExpr::Absolute {
+                day,
+                month: Some(month),
+                year: Some(year),
+            } => NaiveDate::from_ymd_opt(*year, month.number_from_month(), *day)
+                .ok_or(ResolveError::NonexistentDate),
+            HumanDateExpr::Absolute {
+                day,
+                month: Some(month),
+                year: None,
+            } => options.year_policy.pick(now, |offset| {
+                NaiveDate::from_ymd_opt(now.year() + offset, month.number_from_month(), *day)
+            }),
+            HumanDateExpr::Absolute {
+                day,
+                month: None,
+                year: Some(year),
+            } => NaiveDate::from_ymd_opt(*year, now.month(), *day)
+                .ok_or(ResolveError::NonexistentDate),
+            HumanDateExpr::Absolute {
+                day,
+                month: None,
+                year: None,
+            } => {
+                let this_month = now.with_day(1).ok_or(ResolveError::Overflow)?;
+                options.year_policy.pick(now, |offset| {
+                    TimeUnit::Month
+                        .checked_shift(&this_month, offset)?
+                        .with_day(*day)
+                })
+            }
+            HumanDateExpr::Time(_) => Ok(*now),
+            HumanDateExpr::DateTime(date, _) => date.try_relative_to_with(now, options),
+            HumanDateExpr::RelativePeriod(_, _) | HumanDateExpr::Range(_, _) => {
+                Err(ResolveError::Unsupported)
+            }
+        }
+    }
+
+    /// Resolves a [`HumanDateExpr::Range`] or [`HumanDateExpr::RelativePeriod`]
+    /// to its first and last days. Any other expression is a range of a single day.
+    pub fn range_relative_to(&self, now: &NaiveDate) -> Option<(NaiveDate, NaiveDate)> {
+        self.try_range_relative_to(now).ok()
+    }
+
+    pub fn try_range_relative_to(
+        &self,
+        now: &NaiveDate,
+    ) -> Result<(NaiveDate, NaiveDate), ResolveError> {
+        self.try_range_relative_to_with(now, &ResolveOptions::default())
+    }
+
+    /// A range goes from the first day of its start to the last day of its end.
+    /// An end that falls before the start is resolved again relative to the
+    /// start, so "de sexta a segunda" spans a weekend and "de 20 de dezembro a
+    /// 5 de janeiro" a new year. If it still comes first, the range is inverted.
+    pub fn try_range_relative_to_with(
+        &self,
+        now: &NaiveDate,
+        options: &ResolveOptions,
+    ) -> Result<(NaiveDate, NaiveDate), ResolveError> {
+        match self {
+            HumanDateExpr::Range(start, end) => {
+                // The occurrence that contains `now` comes first, like the
+                // December to January holidays on January 2. Otherwise the
+                // year policy picks the start.
+                let past = ResolveOptions {
+                    year_policy: YearPolicy::PreferPast,
+                    ..options.clone()
+                };
+                match Self::try_bounds_relative_to_with(start, end, now, &past, options) {
+                    Ok((start, end)) if start <= *now && *now <= end => Ok((start, end)),
+                    _ => Self::try_bounds_relative_to_with(start, end, now, options, options),
+                }
+            }
+            HumanDateExpr::RelativePeriod(period, n) => period.relative_to(now, *n, options),
+            HumanDateExpr::Offset {
+                base,
+                delta: (n, unit),
+                direction,
+            } => {
+                let (start, end) = base.try_range_relative_to_with(now, options)?;
+                Ok((
+                    direction.shift(&start, *n, unit)?,
+                    direction.shift(&end, *n, unit)?,
+                ))
+            }
+            _ => self
+                .try_relative_to_with(now, options)
+                .map(|date| (date, date)),
+        }
+    }
+
+    /// The first day of `start` and the last day of `end`, which is taken from
+    /// the start on when its year is up to the policy or when it would otherwise
+    /// fall before the start.
+    fn try_bounds_relative_to_with(
+        start: &HumanDateExpr,
+        end: &HumanDateExpr,
+        now: &NaiveDate,
+        start_options: &ResolveOptions,
+        options: &ResolveOptions,
+    ) -> Result<(NaiveDate, NaiveDate), ResolveError> {
+        let with_policy = |year_policy| ResolveOptions {
+            year_policy,
+            ..options.clone()
+        };
+        let (future, past) = (
+            with_policy(YearPolicy::PreferFuture),
+            with_policy(YearPolicy::PreferPast),
+        );
+        let (start, _) = start.try_range_relative_to_with(now, start_options)?;
+        let (_, mut end_date) = end.try_range_relative_to_with(now, options)?;
+        // An end whose year is picked by the policy, like "5 de janeiro", is
+        // its first occurrence from the start on.
+        let picks_year = end.try_range_relative_to_with(now, &future)
+            != end.try_range_relative_to_with(now, &past);
+        if picks_year || end_date < start {
+            (_, end_date) = end.try_range_relative_to_with(&start, &future)?;
+        }
+        if end_date < start {
+            return Err(ResolveError::InvertedRange);
+        }
+        Ok((start, end_date))
+    }
+
+    /// Resolves the expression to a date, a date and time, or a range of dates,
+    /// depending on what it names. With a timezone in the context, dates and
+    /// times are [`Resolved::ZonedDateTime`].
+    pub fn resolve<Tz: TimeZone>(
+        &self,
+        context: &ResolveContext<Tz>,
+    ) -> Result<Resolved, ResolveError> {
+        match (
+            self.resolve_local(&context.now, &context.options)?,
+            &context.timezone,
+        ) {
+            (Resolved::DateTime(local), Some(timezone)) => from_local(&local, timezone)
+                .map(|datetime| Resolved::ZonedDateTime(datetime.fixed_offset())),
+            (resolved, _) => Ok(resolved),
+        }
+    }
+
+    fn resolve_local(
+        &self,
+        now: &NaiveDateTime,
+        options: &ResolveOptions,
+    ) -> Result<Resolved, ResolveError> {
+        match self {
+            HumanDateExpr::Time(_) | HumanDateExpr::DateTime(_, _) => self
+                .try_relative_to_datetime_with(now, options)
+                .map(Resolved::DateTime),
+            HumanDateExpr::Range(_, _) | HumanDateExpr::RelativePeriod(_, _) => self
+                .try_range_relative_to_with(&now.date(), options)
+                .map(|(start, end)| Resolved::Range(start, end)),
+            HumanDateExpr::Offset {
+                base,
+                delta: (n, unit),
+                direction,
+            } => {
+                let shift = |date: NaiveDate| direction.shift(&date, *n, unit);
+                Ok(match base.resolve_local(now, options)? {
+                    Resolved::Date(date) => Resolved::Date(shift(date)?),
+                    Resolved::DateTime(datetime) => {
+                        Resolved::DateTime(shift(datetime.date())?.and_time(datetime.time()))
+                    }
+                    Resolved::Range(start, end) => Resolved::Range(shift(start)?, shift(end)?),
+                    // Only `resolve` attaches a timezone, after this.
+                    Resolved::ZonedDateTime(_) => return Err(ResolveError::Unsupported),
+                })
+            }
+            _ => self
+                .try_relative_to_with(&now.date(), options)
+                .map(Resolved::Date),
+        }
+    }
+
+    /// Like [`HumanDateExpr::relative_to`], but keeps the time of day. Expressions
+    /// without one resolve to the start of the day.
+    pub fn relative_to_datetime(&self, now: &NaiveDateTime) -> Option<NaiveDateTime> {
+        self.try_relative_to_datetime(now).ok()
+    }
+
+    pub fn try_relative_to_datetime(
+        &self,
+        now: &NaiveDateTime,
+    ) -> Result<NaiveDateTime, ResolveError> {
+        self.try_relative_to_datetime_with(now, &ResolveOptions::default())
+    }
+
+    pub fn try_relative_to_datetime_with(
+        &self,
+        now: &NaiveDateTime,
+        options: &ResolveOptions,
+    ) -> Result<NaiveDateTime, ResolveError> {
+        match self {
+            HumanDateExpr::Time(time) => Ok(now.date().and_time(time.as_naive_time())),
+            HumanDateExpr::DateTime(date, time) => date
+                .try_relative_to_with(&now.date(), options)
+                .map(|date| date.and_time(time.as_naive_time())),
+            HumanDateExpr::Offset {
+                base,
+                delta: (n, unit),
+                direction,
+            } => {
+                let base = base.try_relative_to_datetime_with(now, options)?;
+                let date = direction.shift(&base.date(), *n, unit)?;
+                Ok(date.and_time(base.time()))
             }
-            HumanDateExpr::NextWeekWeekday(weekday) => {
-                let n =
-                    7 + (7 - now.weekday().number_from_sunday() + weekday.number_from_sunday()) % 7;
+            _ => self
+                .try_relative_to_with(&now.date(), options)
+                .map(|date| date.and_time(NaiveTime::MIN)),
+        }
+    }
+
+    /// Like [`HumanDateExpr::relative_to_datetime`], for a reference time in a
+    /// timezone. The expression is resolved against the local date and time
+    /// there, so "amanhã" at 23:30 in São Paulo is the next day in São Paulo,
+    /// whatever the date in UTC.
+    pub fn relative_to_zoned<Tz: TimeZone>(&self, now: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        self.try_relative_to_zoned(now).ok()
+    }
+
+    pub fn try_relative_to_zoned<Tz: TimeZone>(
+        &self,
+        now: &DateTime<Tz>,
+    ) -> Result<DateTime<Tz>, ResolveError> {
+        self.try_relative_to_zoned_with(now, &ResolveOptions::default())
+    }
+
+    /// A local time that happens twice resolves to the first one, and one
+    /// skipped by a daylight saving change, like midnight where it starts at
+    /// midnight, to the first time after the gap.
+    pub fn try_relative_to_zoned_with<Tz: TimeZone>(
+        &self,
+        now: &DateTime<Tz>,
+        options: &ResolveOptions,
+    ) -> Result<DateTime<Tz>, ResolveError> {
+        let local = self.try_relative_to_datetime_with(&now.naive_local(), options)?;
+        from_local(&local, &now.timezone())
+    }
+
+    pub(crate) fn with_time(self, time: Option<HumanTime>) -> HumanDateExpr {
+        match time {
+            Some(time) => HumanDateExpr::DateTime(Box::new(self), time),
+            None => self,
+        }
+    }
+
+    /// Joins two range bounds, giving a day without a month the month and
+    /// year of the other end. Fails if a bare day has nothing to take them from.
+    pub(crate) fn range(start: RangeBound, end: RangeBound) -> Option<HumanDateExpr> {
+        let (start, bare_start) = start.into_expr();
+        let (end, bare_end) = end.into_expr();
+        let start = start.with_month_of(&end);
+        let end = end.with_month_of(&start);
+        let has_month =
+            |expr: &HumanDateExpr| matches!(expr, HumanDateExpr::Absolute { month: Some(_), .. });
+        if (bare_start && !has_month(&start)) || (bare_end && !has_month(&end)) {
+            return None;
+        }
+        Some(HumanDateExpr::Range(Box::new(start), Box::new(end)))
+    }
+
+    fn with_month_of(self, other: &HumanDateExpr) -> HumanDateExpr {
+        match (self, other) {
+            (
+                HumanDateExpr::Absolute {
+                    day,
+                    month: None,
+                    year: None,
+                },
+                HumanDateExpr::Absolute {
+                    month: Some(month),
+                    year,
+                    ..
+                },
+            ) => HumanDateExpr::Absolute {
+                day,
+                month: Some(*month),
+                year: *year,
+            },
+            (expr, _) => expr,
+        }
+    }
+}
+
+/// One end of a range as written: a date expression, or a bare day of the
+/// month like the "10" of "de 10 a 15 de maio".
+pub(crate) enum RangeBound {
+    Date(HumanDateExpr),
+    Day(u32),
+}
+
+impl RangeBound {
+    fn into_expr(self) -> (HumanDateExpr, bool) {
+        match self {
+            RangeBound::Date(expr) => (expr, false),
+            RangeBound::Day(day) => (
+                HumanDateExpr::Absolute {
+                    day,
+                    month: None,
+                    year: None,
+                },
+                true,
+            ),
+        }
+    }
+}
+
+/// The year of an expression, either spelled out ("de 2026") or relative to
+/// the reference date ("do ano que vem" is `Relative(1)`).
+#[derive(Clone, Debug, PartialEq)]
+pub enum Year {
+    Absolute(i32),
+    Relative(i32),
+}
+
+impl Year {
+    fn resolve(&self, now: &NaiveDate) -> Option<i32> {
+        match self {
+            Year::Absolute(year) => Some(*year),
+            Year::Relative(n) => now.year().checked_add(*n),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ResolveOptions {
+    pub year_policy: YearPolicy,
+    /// The first day of calendar weeks, for [`WeekMode::Calendar`] and for
+    /// periods like "esta semana". Sunday by default.
+    pub week_start: Weekday,
+    pub week_mode: WeekMode,
+    /// The weekend and holidays skipped by business-day expressions.
+    pub business_calendar: BusinessCalendar,
+}
+
+impl Default for ResolveOptions {
+    fn default() -> Self {
+        ResolveOptions {
+            year_policy: YearPolicy::default(),
+            week_start: Weekday::Sun,
+            week_mode: WeekMode::default(),
+            business_calendar: BusinessCalendar::default(),
+        }
+    }
+}
+
+impl ResolveOptions {
+    /// The defaults, with the week starting on the locale's first day and the
+    /// locale's country holidays, if built in.
+    pub fn for_locale(locale: &Locale) -> Self {
+        let mut options = ResolveOptions {
+            week_start: locale.week_start(),
+            ..Default::default()
+        };
+        if let Some(holidays) = locale.holidays() {
+            options.business_calendar.holidays = holidays;
+        }
+        options
+    }
+
+    /// The first day of the calendar week `now` is in.
+    fn start_of_week(&self, now: &NaiveDate) -> Option<NaiveDate> {
+        now.checked_sub_days(Days::new(now.weekday().days_since(self.week_start).into()))
+    }
+
+    /// `weekday` in the calendar week `n` weeks after the one `now` is in.
+    fn weekday_of_week(
+        &self,
+        now: &NaiveDate,
+        weekday: &Weekday,
+        n: i32,
+    ) -> Result<NaiveDate, ResolveError> {
+        self.start_of_week(now)
+            .and_then(|week| TimeUnit::Week.checked_shift(&week, n))
+            .and_then(|week| {
+                week.checked_add_days(Days::new(weekday.days_since(self.week_start).into()))
+            })
+            .ok_or(ResolveError::Overflow)
+    }
+}
 
-                Some(now.checked_add_days(Days::new(n.into())).unwrap())
+/// The time `local` names in `timezone`, with the rules of
+/// [`HumanDateExpr::try_relative_to_zoned_with`] for gaps and overlaps.
+fn from_local<Tz: TimeZone>(
+    local: &NaiveDateTime,
+    timezone: &Tz,
+) -> Result<DateTime<Tz>, ResolveError> {
+    match timezone.from_local_datetime(local) {
+        LocalResult::Single(datetime) | LocalResult::Ambiguous(datetime, _) => Ok(datetime),
+        LocalResult::None => (1..=180)
+            .filter_map(|minutes| local.checked_add_signed(TimeDelta::minutes(minutes)))
+            .find_map(|local| timezone.from_local_datetime(&local).earliest())
+            .ok_or(ResolveError::NonexistentDate),
+    }
+}
+
+/// Everything [`HumanDateExpr::resolve`] resolves against: the reference
+/// date and time, the timezone it is local to and the options. A locale's
+/// conventions, like its week start and holidays, are seeded into the options
+/// by [`ResolveContext::for_locale`] and [`ResolveContext::zoned_for_locale`].
+#[derive(Clone, Debug)]
+pub struct ResolveContext<Tz: TimeZone = Utc> {
+    /// The reference date and time, as shown in `timezone` if there is one.
+    pub now: NaiveDateTime,
+    pub timezone: Option<Tz>,
+    pub options: ResolveOptions,
+}
+
+impl ResolveContext {
+    pub fn new(now: NaiveDateTime) -> Self {
+        ResolveContext {
+            now,
+            timezone: None,
+            options: ResolveOptions::default(),
+        }
+    }
+
+    pub fn for_locale(now: NaiveDateTime, locale: &Locale) -> Self {
+        ResolveContext {
+            options: ResolveOptions::for_locale(locale),
+            ..ResolveContext::new(now)
+        }
+    }
+}
+
+impl<Tz: TimeZone> ResolveContext<Tz> {
+    /// Resolves against `now` as seen in its timezone, so "amanhã" at 23:30 in
+    /// São Paulo is the next day there, whatever the date in UTC.
+    pub fn zoned(now: &DateTime<Tz>) -> Self {
+        ResolveContext {
+            now: now.naive_local(),
+            timezone: Some(now.timezone()),
+            options: ResolveOptions::default(),
+        }
+    }
+
+    pub fn zoned_for_locale(now: &DateTime<Tz>, locale: &Locale) -> Self {
+        ResolveContext {
+            options: ResolveOptions::for_locale(locale),
+            ..ResolveContext::zoned(now)
+        }
+    }
+}
+
+/// A resolved expression, in the most precise form it was written in.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Resolved {
+    Date(NaiveDate),
+    DateTime(NaiveDateTime),
+    /// A date and time, when the context has a timezone, with the offset in
+    /// effect then.
+    ZonedDateTime(DateTime<FixedOffset>),
+    /// The first and last days of a range or period.
+    Range(NaiveDate, NaiveDate),
+}
+
+/// What "this" and "next" mean before a weekday, like "esta sexta" and "próxima
+/// sexta". The examples are for a Tuesday.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum WeekMode {
+    /// "this monday" is the next Monday, counting today, and "next monday" the
+    /// Monday a week after it, 13 days away.
+    #[default]
+    Rolling,
+    /// "this monday" is the next Monday, counting today, and "next monday" is
+    /// the next Monday not counting today, the same day.
+    NextOccurrence,
+    /// "this monday" is the Monday of the current calendar week, which may
+    /// have passed, and "next monday" the Monday of the following one. Weeks
+    /// start on [`ResolveOptions::week_start`].
+    Calendar,
+}
+
+/// Which year a date without one, like "primeiro domingo de fevereiro", falls in.
+/// Dates with neither month nor year, like "dia 15", pick their month the same way.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum YearPolicy {
+    /// The next occurrence, counting today.
+    #[default]
+    PreferFuture,
+    /// The last occurrence, counting today.
+    PreferPast,
+    /// Whichever occurrence is closest to today, the future one on a tie.
+    Nearest,
+    /// Always the current year (or month).
+    CurrentYear,
+}
+
+impl YearPolicy {
+    /// Picks among the occurrences `candidate` gives for the years or months around
+    /// the current one, by offset. Occurrences that don't exist are skipped, so
+    /// the search goes up to 4 offsets away, far enough to reach a February 29.
+    fn pick(
+        &self,
+        now: &NaiveDate,
+        candidate: impl Fn(i32) -> Option<NaiveDate>,
+    ) -> Result<NaiveDate, ResolveError> {
+        const REACH: i32 = 4;
+        let picked = match self {
+            YearPolicy::PreferFuture => (0..=REACH).filter_map(&candidate).find(|date| date >= now),
+            YearPolicy::PreferPast => (-REACH..=0)
+                .rev()
+                .filter_map(&candidate)
+                .find(|date| date <= now),
+            YearPolicy::Nearest => (-REACH..=REACH)
+                .filter_map(&candidate)
+                .min_by_key(|date| ((*date - *now).num_days().abs(), date < now)),
+            YearPolicy::CurrentYear => candidate(0),
+        };
+        picked.ok_or(ResolveError::NonexistentDate)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum HumanTime {
+    At(NaiveTime),
+    Period(DayPeriod),
+}
+
+impl HumanTime {
+    pub fn as_naive_time(&self) -> NaiveTime {
+        match self {
+            HumanTime::At(time) => *time,
+            HumanTime::Period(period) => period.as_naive_time(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum DayPeriod {
+    Morning,
+    Afternoon,
+    Evening,
+}
+
+impl DayPeriod {
+    /// The time a bare period like "de manhã" resolves to.
+    pub fn as_naive_time(&self) -> NaiveTime {
+        let hour = match self {
+            DayPeriod::Morning => 9,
+            DayPeriod::Afternoon => 15,
+            DayPeriod::Evening => 20,
+        };
+        NaiveTime::from_hms_opt(hour, 0, 0).unwrap()
+    }
+
+    /// Converts a 12-hour clock reading within this period to a time, so that
+    /// "3 da tarde" is 15:00 and "12 da noite" is midnight.
+    pub(crate) fn at(&self, hour: u32, minute: u32) -> Option<NaiveTime> {
+        let hour = match (self, hour) {
+            (_, 0) | (_, 13..) => return None,
+            (DayPeriod::Morning, 12) | (DayPeriod::Evening, 12) => 0,
+            (DayPeriod::Morning, hour) | (DayPeriod::Afternoon, hour @ 12) => hour,
+            (DayPeriod::Afternoon, hour) | (DayPeriod::Evening, hour) => hour + 12,
+        };
+        NaiveTime::from_hms_opt(hour, minute, 0)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TimeUnit {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Direction {
+    Before,
+    After,
+}
+
+impl Direction {
+    fn shift(&self, date: &NaiveDate, n: u64, unit: &TimeUnit) -> Result<NaiveDate, ResolveError> {
+        match self {
+            Direction::Before => unit.sub_from(date, n),
+            Direction::After => unit.add_to(date, n),
+        }
+    }
+}
+
+fn add_days(date: &NaiveDate, n: u64) -> Result<NaiveDate, ResolveError> {
+    TimeUnit::Day.add_to(date, n)
+}
+
+impl TimeUnit {
+    /// Adds `n` units to `date`. Month and year offsets are clamped to the end of the
+    /// target month, so Jan 31 + 1 month is the last day of February.
+    fn add_to(&self, date: &NaiveDate, n: u64) -> Result<NaiveDate, ResolveError> {
+        self.checked_add_to(date, n).ok_or(ResolveError::Overflow)
+    }
+
+    fn sub_from(&self, date: &NaiveDate, n: u64) -> Result<NaiveDate, ResolveError> {
+        self.checked_sub_from(date, n).ok_or(ResolveError::Overflow)
+    }
+
+    fn checked_add_to(&self, date: &NaiveDate, n: u64) -> Option<NaiveDate> {
+        match self {
+            TimeUnit::Day => date.checked_add_days(Days::new(n)),
+            TimeUnit::Week => date.checked_add_days(Days::new(n.checked_mul(7)?)),
+            TimeUnit::Month => date.checked_add_months(Months::new(n.try_into().ok()?)),
+            TimeUnit::Year => {
+                date.checked_add_months(Months::new(n.checked_mul(12)?.try_into().ok()?))
             }
-            HumanDateExpr::OrdinalWeekdayOfMonth(ordinal, weekday, month) => {
-                NaiveDate::from_weekday_of_month_opt(
-                    now.year(),
-                    month.number_from_month(),
-                    *weekday,
-                    ordinal.as_number(),
-                )
+        }
+    }
+
+    /// Adds `n` units to `date`, or subtracts them if `n` is negative.
+    fn checked_shift(&self, date: &NaiveDate, n: i32) -> Option<NaiveDate> {
+        match n {
+            ..0 => self.checked_sub_from(date, n.unsigned_abs().into()),
+            _ => self.checked_add_to(date, n.unsigned_abs().into()),
+        }
+    }
+
+    fn checked_sub_from(&self, date: &NaiveDate, n: u64) -> Option<NaiveDate> {
+        match self {
+            TimeUnit::Day => date.checked_sub_days(Days::new(n)),
+            TimeUnit::Week => date.checked_sub_days(Days::new(n.checked_mul(7)?)),
+            TimeUnit::Month => date.checked_sub_months(Months::new(n.try_into().ok()?)),
+            TimeUnit::Year => {
+                date.checked_sub_months(Months::new(n.checked_mul(12)?.try_into().ok()?))
             }
         }
     }
 }
 
+/// A span of days named by the calendar. Weeks start on
+/// [`ResolveOptions::week_start`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum Period {
+    Week,
+    Weekend,
+    Month,
+    Year,
+}
+
+impl Period {
+    /// The first and last days of the period `n` periods after the one `now` is
+    /// in. The weekend is the run of [`BusinessCalendar::weekend`] days, and on
+    /// its last day, the current weekend is the one that ends that day.
+    fn relative_to(
+        &self,
+        now: &NaiveDate,
+        n: i32,
+        options: &ResolveOptions,
+    ) -> Result<(NaiveDate, NaiveDate), ResolveError> {
+        let (start, unit) = match self {
+            Period::Week => (options.start_of_week(now), TimeUnit::Week),
+            Period::Weekend => {
+                let (start, end) = options
+                    .business_calendar
+                    .weekend_of(now)
+                    .ok_or(ResolveError::NonexistentDate)?;
+                return TimeUnit::Week
+                    .checked_shift(&start, n)
+                    .zip(TimeUnit::Week.checked_shift(&end, n))
+                    .ok_or(ResolveError::Overflow);
+            }
+            Period::Month => (now.with_day(1), TimeUnit::Month),
+            Period::Year => (now.with_ordinal(1), TimeUnit::Year),
+        };
+        start
+            .and_then(|start| unit.checked_shift(&start, n))
+            .and_then(|start| Some((start, unit.checked_add_to(&start, 1)?.pred_opt()?)))
+            .ok_or(ResolveError::Overflow)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum Ordinal {
     First,
@@ -87,27 +1035,51 @@ pub enum Ordinal {
     Third,
     Fourth,
     Fifth,
+    Last,
+    SecondToLast,
 }
 
 impl Ordinal {
-    fn as_number(&self) -> u8 {
-        match self {
+    /// The date of this occurrence of `weekday` in the month, if there is one.
+    fn weekday_of_month(&self, year: i32, month: &Month, weekday: &Weekday) -> Option<NaiveDate> {
+        let month = month.number_from_month();
+        let n = match self {
             Ordinal::First => 1,
             Ordinal::Second => 2,
             Ordinal::Third => 3,
             Ordinal::Fourth => 4,
             Ordinal::Fifth => 5,
-        }
+            Ordinal::Last | Ordinal::SecondToLast => {
+                let last_day = NaiveDate::from_ymd_opt(year, month, 1)?
+                    .checked_add_months(Months::new(1))?
+                    .pred_opt()?;
+                let mut days_back = (7 + last_day.weekday().num_days_from_monday()
+                    - weekday.num_days_from_monday())
+                    % 7;
+                if *self == Ordinal::SecondToLast {
+                    days_back += 7;
+                }
+                return last_day.checked_sub_days(Days::new(days_back.into()));
+            }
+        };
+        NaiveDate::from_weekday_of_month_opt(year, month, *weekday, n)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use chrono::{Month, NaiveDate, Weekday};
+    use std::{collections::BTreeSet, sync::Arc};
+
+    use chrono::{FixedOffset, Month, NaiveDate, NaiveTime, TimeZone, Weekday};
 
     use crate::locales::Locale;
 
-    use super::{extract_all, HumanDateExpr, HumanDateKeyword, Ordinal};
+    use super::{
+        extract_all, extract_all_with, extract_matches, parse, BusinessCalendar, DayPeriod,
+        Direction, Expected, ExtractOptions, Holiday, HumanDateExpr, HumanDateKeyword, HumanTime,
+        Ordinal, ParseError, Period, ResolveContext, ResolveError, ResolveOptions, Resolved,
+        Suggestion, TimeUnit, WeekMode, Year, YearPolicy,
+    };
 
     #[test]
     fn test_extract_all() {
@@ -155,49 +1127,461 @@ mod tests {
     }
 
     #[test]
-    fn test_keywords() {
-        let now = NaiveDate::from_ymd_opt(2024, 8, 13).unwrap(); // Tue
-        assert_eq!(
-            HumanDateExpr::Keyword(HumanDateKeyword::Today).relative_to(&now),
-            NaiveDate::from_ymd_opt(2024, 8, 13)
-        );
+    fn test_extract_all_skips_non_dates() {
+        let items = extract_all(&mut "nos vemos mañana por la mañana", &Locale::Spanish);
         assert_eq!(
-            HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow).relative_to(&now),
-            NaiveDate::from_ymd_opt(2024, 8, 14)
+            items,
+            vec![HumanDateExpr::DateTime(
+                Box::new(HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow)),
+                HumanTime::Period(DayPeriod::Morning)
+            )]
         );
+
+        let items = extract_all(&mut "mañana trabajo toda la mañana", &Locale::Spanish);
         assert_eq!(
-            HumanDateExpr::Keyword(HumanDateKeyword::AfterTomorrow).relative_to(&now),
-            NaiveDate::from_ymd_opt(2024, 8, 15)
+            items,
+            vec![HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow)]
         );
+
+        let items = extract_all(&mut "corro cada mañana", &Locale::Spanish);
+        assert_eq!(items, vec![]);
+
+        // "la mañana" inside "hola", "vuela" and "escuela" is not a non-date.
+        for input in [
+            "hola mañana nos vemos",
+            "ella vuela mañana",
+            "voy a la escuela mañana",
+        ] {
+            assert_eq!(
+                extract_all(&mut &*input, &Locale::Spanish),
+                vec![HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow)],
+                "{}",
+                input
+            );
+        }
     }
 
     #[test]
-    fn test_in_n_days() {
-        let now = NaiveDate::from_ymd_opt(2024, 8, 13).unwrap(); // Tue
-        assert_eq!(
-            HumanDateExpr::InNDays(2).relative_to(&now),
-            NaiveDate::from_ymd_opt(2024, 8, 15)
+    fn test_extract_all_word_boundaries() {
+        let items = extract_all(
+            &mut "problema de segurança no domínio interno",
+            &Locale::BrazilianPortuguese,
         );
-    }
+        assert_eq!(items, vec![]);
 
-    #[test]
-    fn test_this_week_weekday() {
-        let now = NaiveDate::from_ymd_opt(2024, 8, 13).unwrap(); // Tue
+        let items = extract_all(&mut "(seg) e dom.", &Locale::BrazilianPortuguese);
         assert_eq!(
-            HumanDateExpr::ThisWeekWeekday(Weekday::Mon).relative_to(&now),
-            NaiveDate::from_ymd_opt(2024, 8, 19)
+            items,
+            vec![
+                HumanDateExpr::ThisWeekWeekday(Weekday::Mon),
+                HumanDateExpr::ThisWeekWeekday(Weekday::Sun)
+            ]
         );
+        let items = extract_all(&mut "(seg) e dom", &Locale::BrazilianPortuguese);
+        assert_eq!(items, vec![HumanDateExpr::ThisWeekWeekday(Weekday::Mon)]);
+
+        // Portuguese words that are also weekday abbreviations.
+        for input in ["vou ter que ir", "o sex", "o dom da palavra", "qua qua qua"] {
+            assert_eq!(
+                extract_all(&mut &*input, &Locale::BrazilianPortuguese),
+                vec![],
+                "{}",
+                input
+            );
+        }
         assert_eq!(
-            HumanDateExpr::ThisWeekWeekday(Weekday::Tue).relative_to(&now),
-            NaiveDate::from_ymd_opt(2024, 8, 13)
+            extract_all(&mut "ter. ou esta sex", &Locale::BrazilianPortuguese),
+            vec![
+                HumanDateExpr::ThisWeekWeekday(Weekday::Tue),
+                HumanDateExpr::ThisWeekWeekday(Weekday::Fri)
+            ]
         );
+
+        let items = extract_all(&mut "Tuesday todays", &Locale::English);
+        assert_eq!(items, vec![HumanDateExpr::ThisWeekWeekday(Weekday::Tue)]);
+
+        // English words that are also date abbreviations or numbers.
+        for input in [
+            "I may 1 day",
+            "turn the sun on",
+            "sat down",
+            "wed in June",
+            "a week",
+        ] {
+            assert_eq!(
+                extract_all(&mut &*input, &Locale::English),
+                vec![],
+                "{}",
+                input
+            );
+        }
+        // Spanish words that are also weekday abbreviations.
+        for input in ["mar azul", "dom perignon", "el mar"] {
+            assert_eq!(
+                extract_all(&mut &*input, &Locale::Spanish),
+                vec![],
+                "{}",
+                input
+            );
+        }
         assert_eq!(
-            HumanDateExpr::ThisWeekWeekday(Weekday::Wed).relative_to(&now),
-            NaiveDate::from_ymd_opt(2024, 8, 14)
+            extract_all(&mut "el mar. o el dom", &Locale::Spanish),
+            vec![HumanDateExpr::ThisWeekWeekday(Weekday::Tue)]
         );
+
         assert_eq!(
-            HumanDateExpr::ThisWeekWeekday(Weekday::Thu).relative_to(&now),
-            NaiveDate::from_ymd_opt(2024, 8, 15)
+            extract_all(&mut "see you next sat. or on may 3", &Locale::English),
+            vec![
+                HumanDateExpr::NextWeekWeekday(Weekday::Sat),
+                HumanDateExpr::Absolute {
+                    day: 3,
+                    month: Some(Month::May),
+                    year: None
+                },
+            ]
+        );
+
+        // A longer reading that ends inside a word falls back to a shorter one.
+        for input in [
+            "reunião amanhã às 10hs",
+            "amanhã às 10hrs",
+            "amanhã de manhãzinha",
+        ] {
+            assert_eq!(
+                extract_all(&mut &*input, &Locale::BrazilianPortuguese),
+                vec![HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow)],
+                "{}",
+                input
+            );
+        }
+
+        let options = ExtractOptions {
+            word_boundaries: false,
+        };
+        let items = extract_all_with(&mut "segurança", &Locale::BrazilianPortuguese, &options);
+        assert_eq!(items, vec![HumanDateExpr::ThisWeekWeekday(Weekday::Mon)]);
+    }
+
+    #[test]
+    fn test_extract_all_skips_numbers_after_a() {
+        for input in [
+            "itens de 1 a 3",
+            "vou a 10 lojas",
+            "2 a 1",
+            "da página 3 a 7",
+            "vamos as 3 lojas",
+            "as 10 pessoas vieram",
+        ] {
+            assert_eq!(
+                extract_all(&mut &*input, &Locale::BrazilianPortuguese),
+                vec![],
+                "{}",
+                input
+            );
+        }
+        assert_eq!(
+            extract_all(&mut "reunião à 1h da tarde", &Locale::BrazilianPortuguese),
+            vec![HumanDateExpr::Time(HumanTime::At(
+                NaiveTime::from_hms_opt(13, 0, 0).unwrap()
+            ))]
+        );
+        assert_eq!(
+            extract_all(&mut "vamos às 3 lojas", &Locale::BrazilianPortuguese),
+            vec![]
+        );
+        assert_eq!(
+            extract_all(&mut "vamos às 3.", &Locale::BrazilianPortuguese),
+            vec![HumanDateExpr::Time(HumanTime::At(
+                NaiveTime::from_hms_opt(3, 0, 0).unwrap()
+            ))]
+        );
+    }
+
+    #[test]
+    fn test_extract_matches() {
+        let input = "reunião amanhã às 15h e entrega em 3 dias";
+        let matches = extract_matches(&mut &*input, &Locale::BrazilianPortuguese);
+        assert_eq!(matches.len(), 2);
+
+        assert_eq!(
+            matches[0].expr,
+            HumanDateExpr::DateTime(
+                Box::new(HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow)),
+                HumanTime::At(NaiveTime::from_hms_opt(15, 0, 0).unwrap())
+            )
+        );
+        assert_eq!(matches[0].text, "amanhã às 15h");
+        assert_eq!((matches[0].start, matches[0].end), (9, 24));
+        assert_eq!((matches[0].char_start, matches[0].char_end), (8, 21));
+        assert_eq!(&input[matches[0].start..matches[0].end], matches[0].text);
+
+        assert_eq!(matches[1].expr, HumanDateExpr::InN(3, TimeUnit::Day));
+        assert_eq!(matches[1].text, "em 3 dias");
+        assert_eq!((matches[1].start, matches[1].end), (35, 44));
+        assert_eq!((matches[1].char_start, matches[1].char_end), (32, 41));
+        assert_eq!(
+            input.chars().skip(32).take(9).collect::<String>(),
+            "em 3 dias"
+        );
+    }
+
+    #[test]
+    fn test_parse_folds_case_and_accents() {
+        let locale = Locale::BrazilianPortuguese;
+        for input in ["Amanhã", "AMANHA", "amanha", "AmAnHã"] {
+            assert_eq!(
+                parse(&mut &*input, &locale),
+                Ok(HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow))
+            );
+        }
+        assert_eq!(
+            parse(&mut "HOJE", &locale),
+            Ok(HumanDateExpr::Keyword(HumanDateKeyword::Today))
+        );
+        assert_eq!(
+            parse(&mut "Próxima Segunda", &locale),
+            Ok(HumanDateExpr::NextWeekWeekday(Weekday::Mon))
+        );
+        assert_eq!(
+            parse(&mut "PASADO MAÑANA", &Locale::Spanish),
+            Ok(HumanDateExpr::Keyword(HumanDateKeyword::AfterTomorrow))
+        );
+        assert_eq!(
+            parse(&mut "Next Friday", &Locale::English),
+            Ok(HumanDateExpr::NextWeekWeekday(Weekday::Fri))
+        );
+        assert_eq!(
+            parse(&mut "SUNDAY", &Locale::English),
+            Ok(HumanDateExpr::ThisWeekWeekday(Weekday::Sun))
+        );
+        assert_eq!(
+            parse(&mut "Aug 15th, 2025", &Locale::English),
+            Ok(HumanDateExpr::Absolute {
+                day: 15,
+                month: Some(Month::August),
+                year: Some(2025)
+            })
+        );
+
+        let err = parse(&mut "Amanhã xyz", &locale).unwrap_err();
+        assert_eq!(err.offset(), "Amanhã".len());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let locale = Locale::BrazilianPortuguese;
+        assert_eq!(parse(&mut "", &locale), Err(ParseError::Empty));
+
+        let err = parse(&mut "proxma segunda", &locale).unwrap_err();
+        assert!(matches!(err, ParseError::Unrecognized { offset: 4, .. }));
+        assert_eq!(
+            err.suggestion(),
+            Some(&Suggestion {
+                start: 0,
+                end: 6,
+                replacement: "próxima"
+            })
+        );
+
+        let err = parse(&mut "xyz", &locale).unwrap_err();
+        assert_eq!(err.offset(), 0);
+        assert!(err.expected().contains(&Expected::Keyword));
+
+        assert_eq!(
+            parse(&mut "em três xyz", &locale),
+            Err(ParseError::Unrecognized {
+                offset: "em três ".len(),
+                expected: vec![Expected::Unit],
+                suggestion: None
+            })
+        );
+        assert_eq!(
+            parse(&mut "Próxima xyz", &locale),
+            Err(ParseError::Unrecognized {
+                offset: "Próxima ".len(),
+                expected: vec![Expected::Weekday],
+                suggestion: None
+            })
+        );
+
+        let err = parse(&mut "em três diaz", &locale).unwrap_err();
+        assert_eq!(
+            err.suggestion(),
+            Some(&Suggestion {
+                start: 9,
+                end: 13,
+                replacement: "dias"
+            })
+        );
+
+        let err = parse(&mut "amanah", &locale).unwrap_err();
+        assert_eq!(err.suggestion().map(|s| s.replacement), Some("amanhã"));
+
+        let err = parse(&mut "Amanhã xyz", &locale).unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::TrailingInput {
+                offset: "Amanhã".len(),
+                expected: vec![Expected::Time, Expected::End],
+                suggestion: None
+            }
+        );
+
+        let err = parse(&mut "el miercols", &Locale::Spanish).unwrap_err();
+        assert_eq!(err.suggestion().map(|s| s.replacement), Some("miércoles"));
+        assert_eq!(
+            err.to_string(),
+            "unrecognized date expression at offset 6; did you mean \"miércoles\"?"
+        );
+    }
+
+    #[test]
+    fn test_extract_matches_maps_folded_spans() {
+        let input = "Até AMANHÃ às 10h, ou Sábado";
+        let matches = extract_matches(&mut &*input, &Locale::BrazilianPortuguese);
+        assert_eq!(
+            matches.iter().map(|m| m.text).collect::<Vec<_>>(),
+            vec!["AMANHÃ às 10h", "Sábado"]
+        );
+        assert_eq!((matches[0].start, matches[0].end), (5, 20));
+        assert_eq!((matches[0].char_start, matches[0].char_end), (4, 17));
+        assert_eq!(&input[matches[1].start..matches[1].end], "Sábado");
+        assert_eq!((matches[1].char_start, matches[1].char_end), (22, 28));
+    }
+
+    #[test]
+    fn test_keywords() {
+        let now = NaiveDate::from_ymd_opt(2024, 8, 13).unwrap(); // Tue
+        assert_eq!(
+            HumanDateExpr::Keyword(HumanDateKeyword::Today).relative_to(&now),
+            NaiveDate::from_ymd_opt(2024, 8, 13)
+        );
+        assert_eq!(
+            HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow).relative_to(&now),
+            NaiveDate::from_ymd_opt(2024, 8, 14)
+        );
+        assert_eq!(
+            HumanDateExpr::Keyword(HumanDateKeyword::AfterTomorrow).relative_to(&now),
+            NaiveDate::from_ymd_opt(2024, 8, 15)
+        );
+        assert_eq!(
+            HumanDateExpr::Keyword(HumanDateKeyword::Yesterday).relative_to(&now),
+            NaiveDate::from_ymd_opt(2024, 8, 12)
+        );
+        assert_eq!(
+            HumanDateExpr::Keyword(HumanDateKeyword::BeforeYesterday).relative_to(&now),
+            NaiveDate::from_ymd_opt(2024, 8, 11)
+        );
+    }
+
+    #[test]
+    fn test_in_n_days() {
+        let now = NaiveDate::from_ymd_opt(2024, 8, 13).unwrap(); // Tue
+        assert_eq!(
+            HumanDateExpr::InN(2, TimeUnit::Day).relative_to(&now),
+            NaiveDate::from_ymd_opt(2024, 8, 15)
+        );
+        #[allow(deprecated)]
+        let in_n_days = HumanDateExpr::InNDays(2);
+        assert_eq!(
+            in_n_days.relative_to(&now),
+            NaiveDate::from_ymd_opt(2024, 8, 15)
+        );
+        assert_eq!(
+            in_n_days.resolve(&ResolveContext::new(now.and_time(NaiveTime::MIN))),
+            Ok(Resolved::Date(
+                NaiveDate::from_ymd_opt(2024, 8, 15).unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_n_days_ago() {
+        let now = NaiveDate::from_ymd_opt(2024, 8, 13).unwrap(); // Tue
+        assert_eq!(
+            HumanDateExpr::NAgo(3, TimeUnit::Day).relative_to(&now),
+            NaiveDate::from_ymd_opt(2024, 8, 10)
+        );
+        assert_eq!(
+            HumanDateExpr::NAgo(13, TimeUnit::Day).relative_to(&now),
+            NaiveDate::from_ymd_opt(2024, 7, 31)
+        );
+    }
+
+    #[test]
+    fn test_in_n_units() {
+        let now = NaiveDate::from_ymd_opt(2024, 8, 13).unwrap(); // Tue
+        assert_eq!(
+            HumanDateExpr::InN(2, TimeUnit::Week).relative_to(&now),
+            NaiveDate::from_ymd_opt(2024, 8, 27)
+        );
+        assert_eq!(
+            HumanDateExpr::InN(6, TimeUnit::Month).relative_to(&now),
+            NaiveDate::from_ymd_opt(2025, 2, 13)
+        );
+        assert_eq!(
+            HumanDateExpr::InN(1, TimeUnit::Year).relative_to(&now),
+            NaiveDate::from_ymd_opt(2025, 8, 13)
+        );
+        assert_eq!(
+            HumanDateExpr::NAgo(2, TimeUnit::Week).relative_to(&now),
+            NaiveDate::from_ymd_opt(2024, 7, 30)
+        );
+        assert_eq!(
+            HumanDateExpr::NAgo(3, TimeUnit::Month).relative_to(&now),
+            NaiveDate::from_ymd_opt(2024, 5, 13)
+        );
+        assert_eq!(
+            HumanDateExpr::NAgo(1, TimeUnit::Year).relative_to(&now),
+            NaiveDate::from_ymd_opt(2023, 8, 13)
+        );
+        assert_eq!(
+            parse(&mut "daqui a 3 dias", &Locale::BrazilianPortuguese),
+            Ok(HumanDateExpr::InN(3, TimeUnit::Day))
+        );
+    }
+
+    #[test]
+    fn test_in_n_months_clamps_to_end_of_month() {
+        let now = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        assert_eq!(
+            HumanDateExpr::InN(1, TimeUnit::Month).relative_to(&now),
+            NaiveDate::from_ymd_opt(2024, 2, 29)
+        );
+        assert_eq!(
+            HumanDateExpr::InN(3, TimeUnit::Month).relative_to(&now),
+            NaiveDate::from_ymd_opt(2024, 4, 30)
+        );
+        assert_eq!(
+            HumanDateExpr::NAgo(2, TimeUnit::Month).relative_to(&now),
+            NaiveDate::from_ymd_opt(2023, 11, 30)
+        );
+
+        let leap_day = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        assert_eq!(
+            HumanDateExpr::InN(1, TimeUnit::Year).relative_to(&leap_day),
+            NaiveDate::from_ymd_opt(2025, 2, 28)
+        );
+    }
+
+    #[test]
+    fn test_this_week_weekday() {
+        let now = NaiveDate::from_ymd_opt(2024, 8, 13).unwrap(); // Tue
+        assert_eq!(
+            HumanDateExpr::ThisWeekWeekday(Weekday::Mon).relative_to(&now),
+            NaiveDate::from_ymd_opt(2024, 8, 19)
+        );
+        assert_eq!(
+            HumanDateExpr::ThisWeekWeekday(Weekday::Tue).relative_to(&now),
+            NaiveDate::from_ymd_opt(2024, 8, 13)
+        );
+        assert_eq!(
+            HumanDateExpr::ThisWeekWeekday(Weekday::Wed).relative_to(&now),
+            NaiveDate::from_ymd_opt(2024, 8, 14)
+        );
+        assert_eq!(
+            HumanDateExpr::ThisWeekWeekday(Weekday::Thu).relative_to(&now),
+            NaiveDate::from_ymd_opt(2024, 8, 15)
         );
         assert_eq!(
             HumanDateExpr::ThisWeekWeekday(Weekday::Fri).relative_to(&now),
@@ -246,28 +1630,1156 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_weekday_of_next_week() {
+        let now = NaiveDate::from_ymd_opt(2024, 8, 13).unwrap(); // Tue
+        let date = |day| NaiveDate::from_ymd_opt(2024, 8, day);
+        assert_eq!(
+            HumanDateExpr::WeekdayOfNextWeek(Weekday::Mon).relative_to(&now),
+            date(19)
+        );
+        assert_eq!(
+            HumanDateExpr::WeekdayOfNextWeek(Weekday::Sun).relative_to(&now),
+            date(18)
+        );
+        let options = ResolveOptions::for_locale(&Locale::EuropeanPortuguese);
+        for input in [
+            "segunda-feira da próxima semana",
+            "segunda na semana que vem",
+        ] {
+            assert_eq!(
+                parse(&mut &*input, &Locale::EuropeanPortuguese)
+                    .unwrap()
+                    .try_relative_to_with(&now, &options),
+                Ok(date(19).unwrap()),
+                "{}",
+                input
+            );
+        }
+        assert_eq!(
+            parse(&mut "domingo para a semana", &Locale::EuropeanPortuguese)
+                .unwrap()
+                .try_relative_to_with(&now, &options),
+            Ok(date(25).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_week_mode() {
+        let now = NaiveDate::from_ymd_opt(2024, 8, 13).unwrap(); // Tue
+        let resolve = |expr: HumanDateExpr, options: &ResolveOptions| {
+            expr.try_relative_to_with(&now, options).ok()
+        };
+        let date = |day| NaiveDate::from_ymd_opt(2024, 8, day);
+
+        let options = ResolveOptions {
+            week_mode: WeekMode::NextOccurrence,
+            ..Default::default()
+        };
+        assert_eq!(
+            resolve(HumanDateExpr::ThisWeekWeekday(Weekday::Mon), &options),
+            date(19)
+        );
+        assert_eq!(
+            resolve(HumanDateExpr::NextWeekWeekday(Weekday::Mon), &options),
+            date(19)
+        );
+        assert_eq!(
+            resolve(HumanDateExpr::NextWeekWeekday(Weekday::Tue), &options),
+            date(20)
+        );
+        assert_eq!(
+            resolve(HumanDateExpr::NextWeekWeekday(Weekday::Wed), &options),
+            date(14)
+        );
+
+        let options = ResolveOptions {
+            week_mode: WeekMode::Calendar,
+            ..Default::default()
+        };
+        assert_eq!(
+            resolve(HumanDateExpr::ThisWeekWeekday(Weekday::Mon), &options),
+            date(12)
+        );
+        assert_eq!(
+            resolve(HumanDateExpr::NextWeekWeekday(Weekday::Mon), &options),
+            date(19)
+        );
+        assert_eq!(
+            resolve(HumanDateExpr::ThisWeekWeekday(Weekday::Sun), &options),
+            date(11)
+        );
+        assert_eq!(
+            resolve(HumanDateExpr::NextWeekWeekday(Weekday::Sun), &options),
+            date(18)
+        );
+
+        let options = ResolveOptions {
+            week_mode: WeekMode::Calendar,
+            week_start: Weekday::Mon,
+            ..Default::default()
+        };
+        assert_eq!(
+            resolve(HumanDateExpr::ThisWeekWeekday(Weekday::Sun), &options),
+            date(18)
+        );
+        assert_eq!(
+            resolve(HumanDateExpr::NextWeekWeekday(Weekday::Sun), &options),
+            date(25)
+        );
+        assert_eq!(
+            HumanDateExpr::RelativePeriod(Period::Week, 0)
+                .try_range_relative_to_with(&now, &options),
+            Ok((date(12).unwrap(), date(18).unwrap()))
+        );
+    }
+
     #[test]
     fn test_ordinal_weekday_of_month() {
         let now = NaiveDate::from_ymd_opt(2024, 8, 13).unwrap(); // Tue
         assert_eq!(
-            HumanDateExpr::OrdinalWeekdayOfMonth(Ordinal::First, Weekday::Sun, Month::October)
-                .relative_to(&now),
+            HumanDateExpr::OrdinalWeekdayOfMonth(
+                Ordinal::First,
+                Weekday::Sun,
+                Month::October,
+                None
+            )
+            .relative_to(&now),
             NaiveDate::from_ymd_opt(2024, 10, 6)
         );
         assert_eq!(
-            HumanDateExpr::OrdinalWeekdayOfMonth(Ordinal::Second, Weekday::Sun, Month::October)
-                .relative_to(&now),
+            HumanDateExpr::OrdinalWeekdayOfMonth(
+                Ordinal::Second,
+                Weekday::Sun,
+                Month::October,
+                None
+            )
+            .relative_to(&now),
             NaiveDate::from_ymd_opt(2024, 10, 13)
         );
         assert_eq!(
-            HumanDateExpr::OrdinalWeekdayOfMonth(Ordinal::Third, Weekday::Sun, Month::October)
-                .relative_to(&now),
+            HumanDateExpr::OrdinalWeekdayOfMonth(
+                Ordinal::Third,
+                Weekday::Sun,
+                Month::October,
+                None
+            )
+            .relative_to(&now),
             NaiveDate::from_ymd_opt(2024, 10, 20)
         );
         assert_eq!(
-            HumanDateExpr::OrdinalWeekdayOfMonth(Ordinal::Fourth, Weekday::Sun, Month::October)
-                .relative_to(&now),
+            HumanDateExpr::OrdinalWeekdayOfMonth(
+                Ordinal::Fourth,
+                Weekday::Sun,
+                Month::October,
+                None
+            )
+            .relative_to(&now),
+            NaiveDate::from_ymd_opt(2024, 10, 27)
+        );
+    }
+
+    #[test]
+    fn test_last_weekday_of_month() {
+        let now = NaiveDate::from_ymd_opt(2024, 8, 13).unwrap(); // Tue
+        let resolve = |ordinal, weekday, month| {
+            HumanDateExpr::OrdinalWeekdayOfMonth(ordinal, weekday, month, None).relative_to(&now)
+        };
+        assert_eq!(
+            resolve(Ordinal::Last, Weekday::Sun, Month::October),
             NaiveDate::from_ymd_opt(2024, 10, 27)
         );
+        // October 31, 2024 is itself a Thursday.
+        assert_eq!(
+            resolve(Ordinal::Last, Weekday::Thu, Month::October),
+            NaiveDate::from_ymd_opt(2024, 10, 31)
+        );
+        assert_eq!(
+            resolve(Ordinal::SecondToLast, Weekday::Thu, Month::October),
+            NaiveDate::from_ymd_opt(2024, 10, 24)
+        );
+        assert_eq!(
+            resolve(Ordinal::Last, Weekday::Fri, Month::December),
+            NaiveDate::from_ymd_opt(2024, 12, 27)
+        );
+        // Already past this year, so next year's.
+        assert_eq!(
+            resolve(Ordinal::Last, Weekday::Thu, Month::February),
+            NaiveDate::from_ymd_opt(2025, 2, 27)
+        );
+        assert_eq!(
+            resolve(Ordinal::SecondToLast, Weekday::Sat, Month::February),
+            NaiveDate::from_ymd_opt(2025, 2, 15)
+        );
+    }
+
+    #[test]
+    fn test_ordinal_weekday_of_relative_month() {
+        let now = NaiveDate::from_ymd_opt(2024, 12, 20).unwrap();
+        let resolve = |ordinal, weekday, n| {
+            HumanDateExpr::OrdinalWeekdayOfRelativeMonth(ordinal, weekday, n).relative_to(&now)
+        };
+        assert_eq!(
+            resolve(Ordinal::First, Weekday::Mon, 0),
+            NaiveDate::from_ymd_opt(2024, 12, 2)
+        );
+        assert_eq!(
+            resolve(Ordinal::Second, Weekday::Fri, 1),
+            NaiveDate::from_ymd_opt(2025, 1, 10)
+        );
+        assert_eq!(
+            resolve(Ordinal::Last, Weekday::Thu, 2),
+            NaiveDate::from_ymd_opt(2025, 2, 27)
+        );
+        assert_eq!(
+            resolve(Ordinal::Last, Weekday::Sat, -1),
+            NaiveDate::from_ymd_opt(2024, 11, 30)
+        );
+        assert_eq!(resolve(Ordinal::Fifth, Weekday::Mon, 2), None);
+    }
+
+    #[test]
+    fn test_ordinal_weekday_of_month_with_year() {
+        let now = NaiveDate::from_ymd_opt(2024, 8, 13).unwrap(); // Tue
+        assert_eq!(
+            HumanDateExpr::OrdinalWeekdayOfMonth(
+                Ordinal::Second,
+                Weekday::Sun,
+                Month::September,
+                Some(Year::Absolute(2026))
+            )
+            .relative_to(&now),
+            NaiveDate::from_ymd_opt(2026, 9, 13)
+        );
+        assert_eq!(
+            HumanDateExpr::OrdinalWeekdayOfMonth(
+                Ordinal::First,
+                Weekday::Sun,
+                Month::October,
+                Some(Year::Relative(1))
+            )
+            .relative_to(&now),
+            NaiveDate::from_ymd_opt(2025, 10, 5)
+        );
+        // An explicit year wins over the year policy, even in the past.
+        assert_eq!(
+            HumanDateExpr::OrdinalWeekdayOfMonth(
+                Ordinal::First,
+                Weekday::Sun,
+                Month::February,
+                Some(Year::Relative(0))
+            )
+            .relative_to(&now),
+            NaiveDate::from_ymd_opt(2024, 2, 4)
+        );
+        assert_eq!(
+            HumanDateExpr::OrdinalWeekdayOfMonth(
+                Ordinal::First,
+                Weekday::Sun,
+                Month::February,
+                Some(Year::Relative(i32::MAX))
+            )
+            .try_relative_to(&now),
+            Err(ResolveError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_absolute() {
+        let now = NaiveDate::from_ymd_opt(2024, 8, 13).unwrap(); // Tue
+        assert_eq!(
+            HumanDateExpr::Absolute {
+                day: 15,
+                month: Some(Month::August),
+                year: Some(2025)
+            }
+            .relative_to(&now),
+            NaiveDate::from_ymd_opt(2025, 8, 15)
+        );
+        assert_eq!(
+            HumanDateExpr::Absolute {
+                day: 25,
+                month: Some(Month::December),
+                year: None
+            }
+            .relative_to(&now),
+            NaiveDate::from_ymd_opt(2024, 12, 25)
+        );
+        assert_eq!(
+            HumanDateExpr::Absolute {
+                day: 20,
+                month: None,
+                year: None
+            }
+            .relative_to(&now),
+            NaiveDate::from_ymd_opt(2024, 8, 20)
+        );
+        assert_eq!(
+            HumanDateExpr::Absolute {
+                day: 31,
+                month: Some(Month::February),
+                year: None
+            }
+            .relative_to(&now),
+            None
+        );
+    }
+
+    #[test]
+    fn test_year_policy() {
+        let now = NaiveDate::from_ymd_opt(2024, 12, 10).unwrap();
+        let first_sunday_of_february = HumanDateExpr::OrdinalWeekdayOfMonth(
+            Ordinal::First,
+            Weekday::Sun,
+            Month::February,
+            None,
+        );
+        let resolve = |expr: &HumanDateExpr, year_policy| {
+            let options = ResolveOptions {
+                year_policy,
+                ..Default::default()
+            };
+            expr.try_relative_to_with(&now, &options)
+        };
+
+        assert_eq!(
+            first_sunday_of_february.relative_to(&now),
+            NaiveDate::from_ymd_opt(2025, 2, 2)
+        );
+        assert_eq!(
+            resolve(&first_sunday_of_february, YearPolicy::PreferPast),
+            Ok(NaiveDate::from_ymd_opt(2024, 2, 4).unwrap())
+        );
+        assert_eq!(
+            resolve(&first_sunday_of_february, YearPolicy::Nearest),
+            Ok(NaiveDate::from_ymd_opt(2025, 2, 2).unwrap())
+        );
+        assert_eq!(
+            resolve(&first_sunday_of_february, YearPolicy::CurrentYear),
+            Ok(NaiveDate::from_ymd_opt(2024, 2, 4).unwrap())
+        );
+
+        let november_20 = HumanDateExpr::Absolute {
+            day: 20,
+            month: Some(Month::November),
+            year: None,
+        };
+        assert_eq!(
+            november_20.relative_to(&now),
+            NaiveDate::from_ymd_opt(2025, 11, 20)
+        );
+        assert_eq!(
+            resolve(&november_20, YearPolicy::Nearest),
+            Ok(NaiveDate::from_ymd_opt(2024, 11, 20).unwrap())
+        );
+        let december_10 = HumanDateExpr::Absolute {
+            day: 10,
+            month: Some(Month::December),
+            year: None,
+        };
+        assert_eq!(december_10.relative_to(&now), Some(now));
+        assert_eq!(resolve(&december_10, YearPolicy::PreferPast), Ok(now));
+
+        let day_5 = HumanDateExpr::Absolute {
+            day: 5,
+            month: None,
+            year: None,
+        };
+        assert_eq!(day_5.relative_to(&now), NaiveDate::from_ymd_opt(2025, 1, 5));
+        assert_eq!(
+            resolve(&day_5, YearPolicy::PreferPast),
+            Ok(NaiveDate::from_ymd_opt(2024, 12, 5).unwrap())
+        );
+        assert_eq!(
+            resolve(&day_5, YearPolicy::CurrentYear),
+            Ok(NaiveDate::from_ymd_opt(2024, 12, 5).unwrap())
+        );
+
+        // Only leap years have February 29.
+        let now = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let february_29 = HumanDateExpr::Absolute {
+            day: 29,
+            month: Some(Month::February),
+            year: None,
+        };
+        let resolve = |now: &NaiveDate, year_policy| {
+            let options = ResolveOptions {
+                year_policy,
+                ..Default::default()
+            };
+            february_29.try_relative_to_with(now, &options)
+        };
+        assert_eq!(
+            february_29.try_relative_to(&now),
+            Ok(NaiveDate::from_ymd_opt(2028, 2, 29).unwrap())
+        );
+        assert_eq!(
+            resolve(&now, YearPolicy::Nearest),
+            Ok(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap())
+        );
+        let now = NaiveDate::from_ymd_opt(2026, 10, 16).unwrap();
+        assert_eq!(
+            resolve(&now, YearPolicy::PreferPast),
+            Ok(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap())
+        );
+        assert_eq!(
+            resolve(&now, YearPolicy::Nearest),
+            Ok(NaiveDate::from_ymd_opt(2028, 2, 29).unwrap())
+        );
+        assert_eq!(
+            resolve(&now, YearPolicy::CurrentYear),
+            Err(ResolveError::NonexistentDate)
+        );
+        let now = NaiveDate::from_ymd_opt(2025, 1, 10).unwrap();
+        assert_eq!(
+            resolve(&now, YearPolicy::Nearest),
+            Ok(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_try_relative_to() {
+        let now = NaiveDate::from_ymd_opt(2024, 8, 13).unwrap();
+        assert_eq!(
+            HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow).try_relative_to(&now),
+            Ok(NaiveDate::from_ymd_opt(2024, 8, 14).unwrap())
+        );
+
+        let tomorrow = HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow);
+        assert_eq!(
+            tomorrow.try_relative_to(&NaiveDate::MAX),
+            Err(ResolveError::Overflow)
+        );
+        assert_eq!(tomorrow.relative_to(&NaiveDate::MAX), None);
+        assert_eq!(
+            HumanDateExpr::Keyword(HumanDateKeyword::BeforeYesterday)
+                .try_relative_to(&NaiveDate::MIN),
+            Err(ResolveError::Overflow)
+        );
+        assert_eq!(
+            HumanDateExpr::NextWeekWeekday(Weekday::Mon).try_relative_to(&NaiveDate::MAX),
+            Err(ResolveError::Overflow)
+        );
+        assert_eq!(
+            HumanDateExpr::ThisWeekWeekday(Weekday::Sun).try_relative_to(&NaiveDate::MAX),
+            Err(ResolveError::Overflow)
+        );
+
+        let expr = parse(&mut "em 999999999999 dias", &Locale::BrazilianPortuguese).unwrap();
+        assert_eq!(expr.try_relative_to(&now), Err(ResolveError::Overflow));
+        assert_eq!(
+            HumanDateExpr::NAgo(u64::MAX, TimeUnit::Year).try_relative_to(&now),
+            Err(ResolveError::Overflow)
+        );
+
+        // February 2024 has four Sundays.
+        assert_eq!(
+            HumanDateExpr::OrdinalWeekdayOfMonth(
+                Ordinal::Fifth,
+                Weekday::Sun,
+                Month::February,
+                None
+            )
+            .try_relative_to(&now),
+            Err(ResolveError::NonexistentDate)
+        );
+        assert_eq!(
+            HumanDateExpr::Absolute {
+                day: 30,
+                month: Some(Month::February),
+                year: None
+            }
+            .try_relative_to(&now),
+            Err(ResolveError::NonexistentDate)
+        );
+
+        let now = now.and_hms_opt(12, 0, 0).unwrap();
+        assert_eq!(
+            HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow)
+                .try_relative_to_datetime(&NaiveDate::MAX.and_hms_opt(0, 0, 0).unwrap()),
+            Err(ResolveError::Overflow)
+        );
+        assert_eq!(
+            HumanDateExpr::Time(HumanTime::Period(DayPeriod::Evening))
+                .try_relative_to_datetime(&now),
+            Ok(NaiveDate::from_ymd_opt(2024, 8, 13)
+                .unwrap()
+                .and_hms_opt(20, 0, 0)
+                .unwrap())
+        );
+    }
+
+    #[test]
+    fn test_range() {
+        // Wednesday
+        let now = NaiveDate::from_ymd_opt(2024, 8, 14).unwrap();
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let range = |start, end| HumanDateExpr::Range(Box::new(start), Box::new(end));
+
+        let weekdays = range(
+            HumanDateExpr::ThisWeekWeekday(Weekday::Mon),
+            HumanDateExpr::ThisWeekWeekday(Weekday::Fri),
+        );
+        assert_eq!(
+            weekdays.range_relative_to(&now),
+            Some((date(2024, 8, 19), date(2024, 8, 23)))
+        );
+        assert_eq!(
+            weekdays.try_relative_to(&now),
+            Err(ResolveError::Unsupported)
+        );
+
+        let weekend = range(
+            HumanDateExpr::ThisWeekWeekday(Weekday::Fri),
+            HumanDateExpr::ThisWeekWeekday(Weekday::Mon),
+        );
+        assert_eq!(
+            weekend.range_relative_to(&now),
+            Some((date(2024, 8, 16), date(2024, 8, 19)))
+        );
+
+        let holidays = range(
+            HumanDateExpr::Absolute {
+                day: 20,
+                month: Some(Month::December),
+                year: None,
+            },
+            HumanDateExpr::Absolute {
+                day: 5,
+                month: Some(Month::January),
+                year: None,
+            },
+        );
+        assert_eq!(
+            holidays.range_relative_to(&date(2024, 12, 25)),
+            Some((date(2024, 12, 20), date(2025, 1, 5)))
+        );
+        assert_eq!(
+            holidays.range_relative_to(&date(2025, 1, 2)),
+            Some((date(2024, 12, 20), date(2025, 1, 5)))
+        );
+        assert_eq!(
+            holidays.range_relative_to(&date(2024, 12, 1)),
+            Some((date(2024, 12, 20), date(2025, 1, 5)))
+        );
+        assert_eq!(
+            holidays.range_relative_to(&date(2025, 1, 10)),
+            Some((date(2025, 12, 20), date(2026, 1, 5)))
+        );
+        let prefer_past = ResolveOptions {
+            year_policy: YearPolicy::PreferPast,
+            ..Default::default()
+        };
+        assert_eq!(
+            holidays.try_range_relative_to_with(&date(2025, 1, 10), &prefer_past),
+            Ok((date(2024, 12, 20), date(2025, 1, 5)))
+        );
+        let expr = parse(
+            &mut "de 20 de dezembro a 5 de janeiro",
+            &Locale::BrazilianPortuguese,
+        )
+        .unwrap();
+        assert_eq!(
+            expr.range_relative_to(&date(2025, 1, 2)),
+            Some((date(2024, 12, 20), date(2025, 1, 5)))
+        );
+
+        let inverted = range(
+            HumanDateExpr::Absolute {
+                day: 15,
+                month: Some(Month::May),
+                year: Some(2024),
+            },
+            HumanDateExpr::Absolute {
+                day: 10,
+                month: Some(Month::May),
+                year: Some(2024),
+            },
+        );
+        assert_eq!(
+            inverted.try_range_relative_to(&now),
+            Err(ResolveError::InvertedRange)
+        );
+        assert_eq!(
+            range(
+                HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow),
+                HumanDateExpr::Keyword(HumanDateKeyword::Yesterday),
+            )
+            .try_range_relative_to(&now),
+            Err(ResolveError::InvertedRange)
+        );
+
+        assert_eq!(
+            HumanDateExpr::Keyword(HumanDateKeyword::Today).range_relative_to(&now),
+            Some((now, now))
+        );
+    }
+
+    #[test]
+    fn test_relative_period() {
+        // Wednesday
+        let now = NaiveDate::from_ymd_opt(2024, 8, 14).unwrap();
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let resolve = |period, n, now: NaiveDate| {
+            HumanDateExpr::RelativePeriod(period, n).range_relative_to(&now)
+        };
+
+        assert_eq!(
+            resolve(Period::Week, 0, now),
+            Some((date(2024, 8, 11), date(2024, 8, 17)))
+        );
+        assert_eq!(
+            resolve(Period::Week, 1, now),
+            Some((date(2024, 8, 18), date(2024, 8, 24)))
+        );
+        assert_eq!(
+            resolve(Period::Week, -1, now),
+            Some((date(2024, 8, 4), date(2024, 8, 10)))
+        );
+        assert_eq!(
+            resolve(Period::Weekend, 0, now),
+            Some((date(2024, 8, 17), date(2024, 8, 18)))
+        );
+        assert_eq!(
+            resolve(Period::Weekend, 0, date(2024, 8, 18)),
+            Some((date(2024, 8, 17), date(2024, 8, 18)))
+        );
+        assert_eq!(
+            resolve(Period::Weekend, 1, now),
+            Some((date(2024, 8, 24), date(2024, 8, 25)))
+        );
+        let options = ResolveOptions {
+            business_calendar: BusinessCalendar {
+                weekend: vec![Weekday::Fri, Weekday::Sat],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert_eq!(
+            HumanDateExpr::RelativePeriod(Period::Weekend, 0)
+                .try_range_relative_to_with(&now, &options),
+            Ok((date(2024, 8, 16), date(2024, 8, 17)))
+        );
+        assert_eq!(
+            HumanDateExpr::RelativePeriod(Period::Weekend, 0)
+                .try_range_relative_to_with(&date(2024, 8, 17), &options),
+            Ok((date(2024, 8, 16), date(2024, 8, 17)))
+        );
+        assert_eq!(
+            HumanDateExpr::RelativePeriod(Period::Weekend, 0)
+                .try_range_relative_to_with(&date(2024, 8, 18), &options),
+            Ok((date(2024, 8, 23), date(2024, 8, 24)))
+        );
+        assert_eq!(
+            HumanDateExpr::RelativePeriod(Period::Weekend, 0).try_range_relative_to_with(
+                &now,
+                &ResolveOptions {
+                    business_calendar: BusinessCalendar {
+                        weekend: vec![],
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }
+            ),
+            Err(ResolveError::NonexistentDate)
+        );
+        assert_eq!(
+            resolve(Period::Month, 0, now),
+            Some((date(2024, 8, 1), date(2024, 8, 31)))
+        );
+        assert_eq!(
+            resolve(Period::Month, -1, date(2024, 3, 31)),
+            Some((date(2024, 2, 1), date(2024, 2, 29)))
+        );
+        assert_eq!(
+            resolve(Period::Year, 1, now),
+            Some((date(2025, 1, 1), date(2025, 12, 31)))
+        );
+        assert_eq!(
+            HumanDateExpr::RelativePeriod(Period::Week, 1).try_relative_to(&now),
+            Err(ResolveError::Unsupported)
+        );
+        assert_eq!(
+            HumanDateExpr::Range(
+                Box::new(HumanDateExpr::Keyword(HumanDateKeyword::Today)),
+                Box::new(HumanDateExpr::RelativePeriod(Period::Month, 1)),
+            )
+            .range_relative_to(&now),
+            Some((now, date(2024, 9, 30)))
+        );
+    }
+
+    #[test]
+    fn test_extract_all_ranges() {
+        assert_eq!(
+            extract_all(
+                &mut "Trabalho de segunda a sexta, folga entre 10 e 15 de maio",
+                &Locale::BrazilianPortuguese
+            ),
+            vec![
+                HumanDateExpr::Range(
+                    Box::new(HumanDateExpr::ThisWeekWeekday(Weekday::Mon)),
+                    Box::new(HumanDateExpr::ThisWeekWeekday(Weekday::Fri)),
+                ),
+                HumanDateExpr::Range(
+                    Box::new(HumanDateExpr::Absolute {
+                        day: 10,
+                        month: Some(Month::May),
+                        year: None,
+                    }),
+                    Box::new(HumanDateExpr::Absolute {
+                        day: 15,
+                        month: Some(Month::May),
+                        year: None,
+                    }),
+                ),
+            ]
+        );
+        // Bare days need a month from the other end to make a range.
+        assert!(
+            !extract_all(&mut "de 2 a 3 semanas", &Locale::BrazilianPortuguese)
+                .iter()
+                .any(|expr| matches!(expr, HumanDateExpr::Range(_, _)))
+        );
+    }
+
+    #[test]
+    fn test_relative_to_zoned() {
+        let sao_paulo = FixedOffset::west_opt(3 * 3600).unwrap();
+        let now = sao_paulo.with_ymd_and_hms(2024, 8, 13, 23, 30, 0).unwrap();
+        assert_eq!(
+            now.naive_utc().date(),
+            NaiveDate::from_ymd_opt(2024, 8, 14).unwrap()
+        );
+        assert_eq!(
+            HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow).relative_to_zoned(&now),
+            sao_paulo.with_ymd_and_hms(2024, 8, 14, 0, 0, 0).single()
+        );
+        assert_eq!(
+            HumanDateExpr::Time(HumanTime::At(NaiveTime::from_hms_opt(10, 0, 0).unwrap()))
+                .relative_to_zoned(&now),
+            sao_paulo.with_ymd_and_hms(2024, 8, 13, 10, 0, 0).single()
+        );
+    }
+
+    #[test]
+    fn test_resolve_zoned() {
+        let sao_paulo = FixedOffset::west_opt(3 * 3600).unwrap();
+        let now = sao_paulo.with_ymd_and_hms(2024, 11, 14, 23, 30, 0).unwrap(); // Thu
+        let context = ResolveContext::zoned_for_locale(&now, &Locale::BrazilianPortuguese);
+        assert_eq!(
+            HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow).resolve(&context),
+            Ok(Resolved::Date(
+                NaiveDate::from_ymd_opt(2024, 11, 15).unwrap()
+            ))
+        );
+        assert_eq!(
+            HumanDateExpr::DateTime(
+                Box::new(HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow)),
+                HumanTime::At(NaiveTime::from_hms_opt(10, 0, 0).unwrap())
+            )
+            .resolve(&context),
+            Ok(Resolved::ZonedDateTime(
+                sao_paulo.with_ymd_and_hms(2024, 11, 15, 10, 0, 0).unwrap()
+            ))
+        );
+        // Friday the 15th is a holiday in Brazil.
+        assert_eq!(
+            HumanDateExpr::BusinessDays(1).resolve(&context),
+            Ok(Resolved::Date(
+                NaiveDate::from_ymd_opt(2024, 11, 18).unwrap()
+            ))
+        );
+        assert_eq!(
+            HumanDateExpr::RelativePeriod(Period::Week, 0).resolve(&context),
+            Ok(Resolved::Range(
+                NaiveDate::from_ymd_opt(2024, 11, 10).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 11, 16).unwrap()
+            ))
+        );
+        // Weeks start on Monday in Portugal.
+        assert_eq!(
+            HumanDateExpr::RelativePeriod(Period::Week, 0).resolve(
+                &ResolveContext::zoned_for_locale(&now, &Locale::EuropeanPortuguese)
+            ),
+            Ok(Resolved::Range(
+                NaiveDate::from_ymd_opt(2024, 11, 11).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 11, 17).unwrap()
+            ))
+        );
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn test_relative_to_zoned_across_daylight_saving() {
+        use chrono_tz::America::Sao_Paulo;
+
+        // Daylight saving started at midnight on 2018-11-04, skipping to 01:00.
+        let now = Sao_Paulo.with_ymd_and_hms(2018, 11, 3, 12, 0, 0).unwrap();
+        assert_eq!(
+            HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow).relative_to_zoned(&now),
+            Sao_Paulo.with_ymd_and_hms(2018, 11, 4, 1, 0, 0).single()
+        );
+        assert_eq!(
+            HumanDateExpr::DateTime(
+                Box::new(HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow)),
+                HumanTime::At(NaiveTime::MIN)
+            )
+            .resolve(&ResolveContext::zoned(&now)),
+            Ok(Resolved::ZonedDateTime(
+                Sao_Paulo
+                    .with_ymd_and_hms(2018, 11, 4, 1, 0, 0)
+                    .unwrap()
+                    .fixed_offset()
+            ))
+        );
+        assert_eq!(
+            HumanDateExpr::InN(2, TimeUnit::Day)
+                .relative_to_zoned(&now)
+                .map(|datetime| datetime.offset().to_string()),
+            Some("-02".to_string())
+        );
+    }
+
+    #[test]
+    fn test_business_days() {
+        let now = NaiveDate::from_ymd_opt(2024, 11, 14).unwrap(); // Thu
+        let date = |month, day| NaiveDate::from_ymd_opt(2024, month, day);
+        assert_eq!(
+            HumanDateExpr::BusinessDays(1).relative_to(&now),
+            date(11, 15)
+        );
+        assert_eq!(
+            HumanDateExpr::BusinessDays(3).relative_to(&now),
+            date(11, 19)
+        );
+        assert_eq!(
+            HumanDateExpr::BusinessDays(-4).relative_to(&now),
+            date(11, 8)
+        );
+        let saturday = NaiveDate::from_ymd_opt(2024, 11, 16).unwrap();
+        assert_eq!(
+            HumanDateExpr::BusinessDays(5).relative_to(&saturday),
+            date(11, 22)
+        );
+        assert_eq!(
+            HumanDateExpr::BusinessDayOfRelativeMonth(Ordinal::Last, 0).relative_to(&now),
+            date(11, 29)
+        );
+        assert_eq!(
+            HumanDateExpr::BusinessDayOfRelativeMonth(Ordinal::First, 1).relative_to(&now),
+            date(12, 2)
+        );
+
+        let options = ResolveOptions {
+            business_calendar: BusinessCalendar {
+                holidays: Arc::new(BTreeSet::from([
+                    date(11, 15).unwrap(),
+                    date(11, 20).unwrap(),
+                ])),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert_eq!(
+            HumanDateExpr::BusinessDays(3).try_relative_to_with(&now, &options),
+            Ok(date(11, 21).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_business_days_with_locale_holidays() {
+        let now = NaiveDate::from_ymd_opt(2024, 11, 14).unwrap(); // Thu
+        let options = ResolveOptions::for_locale(&Locale::BrazilianPortuguese);
+        let resolve = |n| HumanDateExpr::BusinessDays(n).try_relative_to_with(&now, &options);
+        // Proclamação da República on the 15th, Consciência Negra on the 20th.
+        assert_eq!(
+            resolve(1),
+            Ok(NaiveDate::from_ymd_opt(2024, 11, 18).unwrap())
+        );
+        assert_eq!(
+            resolve(3),
+            Ok(NaiveDate::from_ymd_opt(2024, 11, 21).unwrap())
+        );
+
+        let options = ResolveOptions::for_locale(&Locale::English);
+        assert_eq!(
+            HumanDateExpr::BusinessDays(1).try_relative_to_with(&now, &options),
+            Ok(NaiveDate::from_ymd_opt(2024, 11, 15).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_holiday() {
+        let now = NaiveDate::from_ymd_opt(2024, 12, 26).unwrap();
+        let date = |year, month, day| NaiveDate::from_ymd_opt(year, month, day);
+        assert_eq!(
+            HumanDateExpr::Holiday(Holiday::Christmas, None).relative_to(&now),
+            date(2025, 12, 25)
+        );
+        assert_eq!(
+            HumanDateExpr::Holiday(Holiday::Carnival, None).relative_to(&now),
+            date(2025, 3, 4)
+        );
+        assert_eq!(
+            HumanDateExpr::Holiday(Holiday::Easter, Some(Year::Absolute(2026))).relative_to(&now),
+            date(2026, 4, 5)
+        );
+        assert_eq!(
+            HumanDateExpr::Holiday(Holiday::Christmas, Some(Year::Relative(0))).relative_to(&now),
+            date(2024, 12, 25)
+        );
+        assert_eq!(
+            HumanDateExpr::Holiday(Holiday::BlackConsciousness, Some(Year::Absolute(2020)))
+                .try_relative_to(&now),
+            Err(ResolveError::NonexistentDate)
+        );
+        assert_eq!(
+            HumanDateExpr::Offset {
+                base: Box::new(HumanDateExpr::Holiday(Holiday::Christmas, None)),
+                delta: (2, TimeUnit::Day),
+                direction: Direction::Before,
+            }
+            .relative_to(&now),
+            date(2025, 12, 23)
+        );
+        assert_eq!(
+            HumanDateExpr::Offset {
+                base: Box::new(HumanDateExpr::Holiday(Holiday::Carnival, None)),
+                delta: (1, TimeUnit::Week),
+                direction: Direction::After,
+            }
+            .relative_to(&now),
+            date(2025, 3, 11)
+        );
+        let expr = parse(&mut "2 dias antes do Natal", &Locale::BrazilianPortuguese).unwrap();
+        assert_eq!(expr.relative_to(&now), date(2025, 12, 23));
+        let expr = parse(&mut "Páscoa de 2026", &Locale::BrazilianPortuguese).unwrap();
+        assert_eq!(expr.relative_to(&now), date(2026, 4, 5));
+        let expr = parse(&mut "no Natal", &Locale::BrazilianPortuguese).unwrap();
+        assert_eq!(expr, HumanDateExpr::Holiday(Holiday::Christmas, None));
+        let expr = parse(&mut "depois do Carnaval", &Locale::BrazilianPortuguese).unwrap();
+        assert_eq!(expr.relative_to(&now), date(2025, 3, 5));
+        let expr = parse(&mut "antes do Natal", &Locale::BrazilianPortuguese).unwrap();
+        assert_eq!(expr.relative_to(&now), date(2025, 12, 24));
+        assert_eq!(
+            extract_all(
+                &mut "promoção depois do Carnaval e na Black Friday",
+                &Locale::BrazilianPortuguese
+            ),
+            vec![
+                HumanDateExpr::Offset {
+                    base: Box::new(HumanDateExpr::Holiday(Holiday::Carnival, None)),
+                    delta: (1, TimeUnit::Day),
+                    direction: Direction::After,
+                },
+                HumanDateExpr::Holiday(Holiday::BlackFriday, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_offset() {
+        let now = NaiveDate::from_ymd_opt(2024, 8, 13)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap(); // Tue
+        let date = |month, day| NaiveDate::from_ymd_opt(2024, month, day).unwrap();
+        let three_pm = NaiveTime::from_hms_opt(15, 0, 0).unwrap();
+        let context = ResolveContext::new(now);
+        let offset = |base, delta, direction| HumanDateExpr::Offset {
+            base: Box::new(base),
+            delta,
+            direction,
+        };
+
+        let expr = offset(
+            HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow),
+            (3, TimeUnit::Day),
+            Direction::After,
+        );
+        assert_eq!(expr.relative_to(&now.date()), Some(date(8, 17)));
+        assert_eq!(expr.resolve(&context), Ok(Resolved::Date(date(8, 17))));
+        let expr = offset(expr, (1, TimeUnit::Month), Direction::Before);
+        assert_eq!(expr.relative_to(&now.date()), Some(date(7, 17)));
+
+        let expr = offset(
+            HumanDateExpr::DateTime(
+                Box::new(HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow)),
+                HumanTime::At(three_pm),
+            ),
+            (1, TimeUnit::Week),
+            Direction::After,
+        );
+        assert_eq!(
+            expr.relative_to_datetime(&now),
+            Some(date(8, 21).and_time(three_pm))
+        );
+        assert_eq!(
+            expr.resolve(&context),
+            Ok(Resolved::DateTime(date(8, 21).and_time(three_pm)))
+        );
+
+        let expr = offset(
+            HumanDateExpr::RelativePeriod(Period::Week, 1),
+            (1, TimeUnit::Week),
+            Direction::After,
+        );
+        assert_eq!(
+            expr.range_relative_to(&now.date()),
+            Some((date(8, 25), date(8, 31)))
+        );
+        assert_eq!(
+            expr.resolve(&context),
+            Ok(Resolved::Range(date(8, 25), date(8, 31)))
+        );
+
+        let expr = parse(
+            &mut "30 dias após o dia 10 de setembro",
+            &Locale::BrazilianPortuguese,
+        )
+        .unwrap();
+        assert_eq!(expr.relative_to(&now.date()), Some(date(10, 10)));
+    }
+
+    #[test]
+    fn test_deeply_nested_offsets() {
+        let input = format!("{}amanhã", "1 dia antes de ".repeat(20_000));
+        assert!(parse(&mut input.as_str(), &Locale::BrazilianPortuguese).is_err());
+
+        let input = format!("{}amanhã", "1 dia antes de ".repeat(1_000));
+        let items = extract_all(&mut input.as_str(), &Locale::BrazilianPortuguese);
+        assert_eq!(items.len(), 1);
+        let mut depth = 0;
+        let mut expr = &items[0];
+        while let HumanDateExpr::Offset { base, .. } = expr {
+            (depth, expr) = (depth + 1, base);
+        }
+        assert_eq!(depth, 8);
+        assert_eq!(expr, &HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow));
+
+        let input = format!("{}amanhã", "1 dia antes de ".repeat(8));
+        let now = NaiveDate::from_ymd_opt(2024, 8, 13).unwrap();
+        let expr = parse(&mut input.as_str(), &Locale::BrazilianPortuguese).unwrap();
+        assert_eq!(expr.relative_to(&now), NaiveDate::from_ymd_opt(2024, 8, 6));
+    }
+
+    #[test]
+    fn test_resolve() {
+        let now = NaiveDate::from_ymd_opt(2024, 8, 13)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap(); // Tue
+        let date = |day| NaiveDate::from_ymd_opt(2024, 8, day).unwrap();
+        let three_pm = NaiveTime::from_hms_opt(15, 0, 0).unwrap();
+        let context = ResolveContext::new(now);
+
+        assert_eq!(
+            HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow).resolve(&context),
+            Ok(Resolved::Date(date(14)))
+        );
+        assert_eq!(
+            HumanDateExpr::DateTime(
+                Box::new(HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow)),
+                HumanTime::At(three_pm)
+            )
+            .resolve(&context),
+            Ok(Resolved::DateTime(date(14).and_time(three_pm)))
+        );
+        assert_eq!(
+            HumanDateExpr::Time(HumanTime::At(three_pm)).resolve(&context),
+            Ok(Resolved::DateTime(date(13).and_time(three_pm)))
+        );
+        assert_eq!(
+            HumanDateExpr::RelativePeriod(Period::Week, 0).resolve(&context),
+            Ok(Resolved::Range(date(11), date(17)))
+        );
+        assert_eq!(
+            HumanDateExpr::RelativePeriod(Period::Week, 0).resolve(&ResolveContext::for_locale(
+                now,
+                &Locale::EuropeanPortuguese
+            )),
+            Ok(Resolved::Range(date(12), date(18)))
+        );
+        assert_eq!(
+            HumanDateExpr::Absolute {
+                day: 30,
+                month: Some(Month::February),
+                year: Some(2024)
+            }
+            .resolve(&context),
+            Err(ResolveError::NonexistentDate)
+        );
+    }
+
+    #[test]
+    fn test_relative_to_datetime() {
+        let now = NaiveDate::from_ymd_opt(2024, 8, 13)
+            .unwrap()
+            .and_hms_opt(18, 45, 0)
+            .unwrap(); // Tue
+        let ten = NaiveTime::from_hms_opt(10, 0, 0).unwrap();
+        assert_eq!(
+            HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow).relative_to_datetime(&now),
+            NaiveDate::from_ymd_opt(2024, 8, 14).map(|date| date.and_time(NaiveTime::MIN))
+        );
+        assert_eq!(
+            HumanDateExpr::Time(HumanTime::At(ten)).relative_to_datetime(&now),
+            NaiveDate::from_ymd_opt(2024, 8, 13).map(|date| date.and_time(ten))
+        );
+        assert_eq!(
+            HumanDateExpr::DateTime(
+                Box::new(HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow)),
+                HumanTime::At(ten)
+            )
+            .relative_to_datetime(&now),
+            NaiveDate::from_ymd_opt(2024, 8, 14).map(|date| date.and_time(ten))
+        );
+        assert_eq!(
+            HumanDateExpr::DateTime(
+                Box::new(HumanDateExpr::ThisWeekWeekday(Weekday::Fri)),
+                HumanTime::Period(DayPeriod::Morning)
+            )
+            .relative_to_datetime(&now),
+            NaiveDate::from_ymd_opt(2024, 8, 16).and_then(|date| date.and_hms_opt(9, 0, 0))
+        );
+        assert_eq!(
+            HumanDateExpr::DateTime(
+                Box::new(HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow)),
+                HumanTime::At(ten)
+            )
+            .relative_to(&now.date()),
+            NaiveDate::from_ymd_opt(2024, 8, 14)
+        );
+    }
+
+    #[test]
+    fn test_day_period_at() {
+        assert_eq!(
+            DayPeriod::Morning.at(9, 30),
+            NaiveTime::from_hms_opt(9, 30, 0)
+        );
+        assert_eq!(
+            DayPeriod::Morning.at(12, 0),
+            NaiveTime::from_hms_opt(0, 0, 0)
+        );
+        assert_eq!(
+            DayPeriod::Afternoon.at(3, 0),
+            NaiveTime::from_hms_opt(15, 0, 0)
+        );
+        assert_eq!(
+            DayPeriod::Afternoon.at(12, 0),
+            NaiveTime::from_hms_opt(12, 0, 0)
+        );
+        assert_eq!(
+            DayPeriod::Evening.at(8, 0),
+            NaiveTime::from_hms_opt(20, 0, 0)
+        );
+        assert_eq!(
+            DayPeriod::Evening.at(12, 0),
+            NaiveTime::from_hms_opt(0, 0, 0)
+        );
+        assert_eq!(DayPeriod::Evening.at(13, 0), None);
+        assert_eq!(DayPeriod::Morning.at(0, 0), None);
     }
 }
@@ -0,0 +1,1134 @@
+use std::str::FromStr;
+
+use chrono::{Month, NaiveTime, Weekday};
+use winnow::{
+    ascii::{digit1, space1},
+    combinator::{alt, not, opt, preceded, terminated},
+    Parser,
+};
+
+use super::range::{range, Connectives};
+use super::{parse_folded, PResult};
+use crate::error::GrammarError;
+
+use super::numeric::{
+    clock_time, date_separator, day_of_month, full_year, hour, minute, month_number, whole_hour,
+    year, NOON,
+};
+use crate::{
+    DayPeriod, Expected, HumanDateExpr, HumanDateKeyword, HumanTime, Ordinal, Period, TimeUnit,
+    Year,
+};
+
+/// Words of this grammar, to suggest corrections for misspelled input.
+pub(crate) const VOCABULARY: &[&str] = &[
+    "today",
+    "tomorrow",
+    "yesterday",
+    "the",
+    "day",
+    "after",
+    "before",
+    "in",
+    "ago",
+    "days",
+    "week",
+    "weeks",
+    "month",
+    "months",
+    "year",
+    "years",
+    "this",
+    "next",
+    "first",
+    "second",
+    "third",
+    "fourth",
+    "fifth",
+    "of",
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+    "mon",
+    "tue",
+    "tues",
+    "wed",
+    "thu",
+    "thurs",
+    "fri",
+    "sat",
+    "sun",
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+    "jan",
+    "feb",
+    "mar",
+    "apr",
+    "jun",
+    "jul",
+    "aug",
+    "sep",
+    "sept",
+    "oct",
+    "nov",
+    "dec",
+    "at",
+    "noon",
+    "midday",
+    "midnight",
+    "am",
+    "pm",
+    "a",
+    "m",
+    "p",
+    "morning",
+    "afternoon",
+    "evening",
+    "night",
+    "tonight",
+    "one",
+    "an",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+    "twenty",
+    "last",
+    "penultimate",
+    "to",
+    "from",
+    "until",
+    "till",
+    "through",
+    "between",
+    "and",
+    "weekend",
+];
+
+#[derive(Default)]
+pub struct HumanDateParserEnglishParser;
+
+impl HumanDateParserEnglishParser {
+    pub fn new() -> Self {
+        HumanDateParserEnglishParser {}
+    }
+}
+
+impl Parser<&str, HumanDateExpr, GrammarError> for HumanDateParserEnglishParser {
+    fn parse_next(&mut self, input: &mut &str) -> PResult<HumanDateExpr> {
+        parse_folded(input, grammar)
+    }
+}
+
+/// The grammar, which only matches folded text.
+pub(crate) fn grammar(input: &mut &str) -> PResult<HumanDateExpr> {
+    alt((
+        range(date, range_day, &CONNECTIVES),
+        (date, opt(preceded(space1, date_time))).map(|(date, time)| date.with_time(time)),
+        time.map(HumanDateExpr::Time),
+    ))
+    .parse_next(input)
+}
+
+fn date(input: &mut &str) -> PResult<HumanDateExpr> {
+    alt((
+        keyword.map(HumanDateExpr::Keyword),
+        in_n.map(|(n, unit)| HumanDateExpr::InN(n, unit)),
+        n_ago.map(|(n, unit)| HumanDateExpr::NAgo(n, unit)),
+        absolute.map(|(day, month, year)| HumanDateExpr::Absolute { day, month, year }),
+        ordinal_weekday_of_month.map(|(ordinal, weekday, month, year)| {
+            HumanDateExpr::OrdinalWeekdayOfMonth(ordinal, weekday, month, year)
+        }),
+        relative_period.map(|(period, n)| HumanDateExpr::RelativePeriod(period, n)),
+        this_week_weekday.map(HumanDateExpr::ThisWeekWeekday),
+        next_week_weekday.map(HumanDateExpr::NextWeekWeekday),
+    ))
+    .parse_next(input)
+}
+
+/// "from monday to friday", "between may 10 and 15", "from today until the
+/// 20th", "monday through friday"...
+const CONNECTIVES: Connectives = Connectives {
+    from: &["from"],
+    to: &["to", "until", "till", "through"],
+    between: "between",
+    and: "and",
+    until: &["until", "till", "through"],
+};
+
+/// The day a range bound can be on its own, like "the 20th".
+fn range_day(input: &mut &str) -> PResult<u32> {
+    preceded(opt(("the", space1)), day).parse_next(input)
+}
+
+fn keyword(input: &mut &str) -> PResult<HumanDateKeyword> {
+    alt((
+        "today".value(HumanDateKeyword::Today),
+        "tomorrow".value(HumanDateKeyword::Tomorrow),
+        "yesterday".value(HumanDateKeyword::Yesterday),
+        preceded(
+            opt(("the", space1)),
+            alt((
+                "day after tomorrow".value(HumanDateKeyword::AfterTomorrow),
+                "day before yesterday".value(HumanDateKeyword::BeforeYesterday),
+            )),
+        ),
+    ))
+    .context(Expected::Keyword.context())
+    .parse_next(input)
+}
+
+fn in_n(input: &mut &str) -> PResult<(u64, TimeUnit)> {
+    preceded(("in", space1), quantity).parse_next(input)
+}
+
+fn n_ago(input: &mut &str) -> PResult<(u64, TimeUnit)> {
+    terminated(quantity, (space1, "ago")).parse_next(input)
+}
+
+fn quantity(input: &mut &str) -> PResult<(u64, TimeUnit)> {
+    // "a" and "an" only count as one right before a unit: "in a week".
+    let (n, _, unit) =
+        (alt((number, alt(("an", "a")).value(1))), space1, unit).parse_next(input)?;
+    Ok((n, unit))
+}
+
+fn unit(input: &mut &str) -> PResult<TimeUnit> {
+    let (unit, _) = (
+        alt((
+            "day".value(TimeUnit::Day),
+            "week".value(TimeUnit::Week),
+            "month".value(TimeUnit::Month),
+            "year".value(TimeUnit::Year),
+        )),
+        opt('s'),
+    )
+        .context(Expected::Unit.context())
+        .parse_next(input)?;
+    Ok(unit)
+}
+
+/// "this week", "next month", "last year", "the weekend"...
+fn relative_period(input: &mut &str) -> PResult<(Period, i32)> {
+    alt((
+        preceded((this, space1), period).map(|period| (period, 0)),
+        preceded((next, space1), period).map(|period| (period, 1)),
+        preceded(("last", space1), period).map(|period| (period, -1)),
+        "the weekend".value((Period::Weekend, 0)),
+    ))
+    .parse_next(input)
+}
+
+fn period(input: &mut &str) -> PResult<Period> {
+    alt((
+        "weekend".value(Period::Weekend),
+        "week".value(Period::Week),
+        "month".value(Period::Month),
+        "year".value(Period::Year),
+    ))
+    .parse_next(input)
+}
+
+fn this_week_weekday(input: &mut &str) -> PResult<Weekday> {
+    alt((
+        preceded((this, space1), weekday),
+        // On their own, these are more often words than weekdays: "sat down".
+        weekday
+            .with_taken()
+            .verify(|(_, taken)| !["mon", "wed", "sat", "sun"].contains(taken))
+            .map(|(weekday, _)| weekday),
+    ))
+    .parse_next(input)
+}
+
+fn next_week_weekday(input: &mut &str) -> PResult<Weekday> {
+    let (_, _, weekday) = (next, space1, weekday).parse_next(input)?;
+    Ok(weekday)
+}
+
+fn ordinal_weekday_of_month(input: &mut &str) -> PResult<(Ordinal, Weekday, Month, Option<Year>)> {
+    let (_, ordinal, _, weekday, _, _, _, month, year) = (
+        opt(("the", space1)),
+        ordinal,
+        space1,
+        weekday,
+        space1,
+        "of",
+        space1,
+        month,
+        opt(which_year),
+    )
+        .parse_next(input)?;
+    Ok((ordinal, weekday, month, year))
+}
+
+/// " 2026", ", 2026", " next year", " of this year"...
+fn which_year(input: &mut &str) -> PResult<Year> {
+    alt((
+        preceded((opt(','), space1), full_year).map(Year::Absolute),
+        preceded(
+            (space1, opt(("of", space1))),
+            alt((
+                (next, space1, "year").value(Year::Relative(1)),
+                (this, space1, "year").value(Year::Relative(0)),
+                "last year".value(Year::Relative(-1)),
+            )),
+        ),
+    ))
+    .parse_next(input)
+}
+
+fn absolute(input: &mut &str) -> PResult<(u32, Option<Month>, Option<i32>)> {
+    alt((
+        numeric_date.map(|(month, day, year)| (day, Some(month), year)),
+        // Not "may 1 day", where "may" is a verb.
+        (
+            month,
+            space1,
+            terminated(day, not((space1, unit))),
+            opt(preceded((opt(','), space1), full_year)),
+        )
+            .map(|(month, _, day, year)| (day, Some(month), year)),
+        (
+            opt(("the", space1)),
+            day,
+            space1,
+            opt(("of", space1)),
+            month,
+            opt(preceded((opt(','), space1), full_year)),
+        )
+            .map(|(_, day, _, _, month, year)| (day, Some(month), year)),
+        preceded(("the", space1), day).map(|day| (day, None, None)),
+    ))
+    .parse_next(input)
+}
+
+/// Month-first numeric dates: "8/15", "08/15/2024" or "8-15-24".
+fn numeric_date(input: &mut &str) -> PResult<(Month, u32, Option<i32>)> {
+    let (month, separator, day) = (month_number, date_separator, day_of_month).parse_next(input)?;
+    let year = opt(preceded(separator, year)).parse_next(input)?;
+    Ok((month, day, year))
+}
+
+/// A day of the month, with the ordinal suffix that matches it if any: "1st",
+/// "22nd", "11th", but not "1th".
+fn day(input: &mut &str) -> PResult<u32> {
+    (day_of_month, opt(alt(("st", "nd", "rd", "th"))))
+        .verify(|(day, suffix)| suffix.is_none_or(|suffix| suffix == ordinal_suffix(*day)))
+        .map(|(day, _)| day)
+        .parse_next(input)
+}
+
+fn ordinal_suffix(n: u32) -> &'static str {
+    match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+/// A time of day following a date, where a bare period is enough: "tomorrow morning".
+fn date_time(input: &mut &str) -> PResult<HumanTime> {
+    alt((time, day_period.map(HumanTime::Period))).parse_next(input)
+}
+
+fn time(input: &mut &str) -> PResult<HumanTime> {
+    alt((
+        preceded(("at", space1), alt((clock, whole_hour))).map(HumanTime::At),
+        clock.map(HumanTime::At),
+        alt((
+            preceded(("in the", space1), day_period),
+            ("at", space1, "night").value(DayPeriod::Evening),
+            "tonight".value(DayPeriod::Evening),
+        ))
+        .map(HumanTime::Period),
+    ))
+    .context(Expected::Time.context())
+    .parse_next(input)
+}
+
+fn clock(input: &mut &str) -> PResult<NaiveTime> {
+    alt((
+        alt(("noon", "midday")).value(NOON),
+        "midnight".value(NaiveTime::MIN),
+        (hour, opt(preceded(':', minute)), opt(space1), meridiem)
+            .verify_map(|(hour, minute, _, period)| period.at(hour, minute.unwrap_or(0))),
+        clock_time,
+    ))
+    .parse_next(input)
+}
+
+fn meridiem(input: &mut &str) -> PResult<DayPeriod> {
+    alt((
+        alt(("a.m.", "am")).value(DayPeriod::Morning),
+        alt(("p.m.", "pm")).value(DayPeriod::Afternoon),
+    ))
+    .parse_next(input)
+}
+
+fn day_period(input: &mut &str) -> PResult<DayPeriod> {
+    alt((
+        "morning".value(DayPeriod::Morning),
+        "afternoon".value(DayPeriod::Afternoon),
+        alt(("evening", "night")).value(DayPeriod::Evening),
+    ))
+    .parse_next(input)
+}
+
+fn this(input: &mut &str) -> PResult<()> {
+    "this".void().parse_next(input)
+}
+
+fn next(input: &mut &str) -> PResult<()> {
+    "next".void().parse_next(input)
+}
+
+fn ordinal(input: &mut &str) -> PResult<Ordinal> {
+    alt((
+        alt(("second to last", "second-to-last", "penultimate")).value(Ordinal::SecondToLast),
+        "last".value(Ordinal::Last),
+        alt(("first", "1st")).value(Ordinal::First),
+        alt(("second", "2nd")).value(Ordinal::Second),
+        alt(("third", "3rd")).value(Ordinal::Third),
+        alt(("fourth", "4th")).value(Ordinal::Fourth),
+        alt(("fifth", "5th")).value(Ordinal::Fifth),
+    ))
+    .parse_next(input)
+}
+
+fn number(input: &mut &str) -> PResult<u64> {
+    alt((
+        digit1.try_map(FromStr::from_str),
+        "seventeen".value(17),
+        "sixteen".value(16),
+        "fourteen".value(14),
+        "eighteen".value(18),
+        "nineteen".value(19),
+        "thirteen".value(13),
+        "fifteen".value(15),
+        "twenty".value(20),
+        "eleven".value(11),
+        "twelve".value(12),
+        "three".value(3),
+        "seven".value(7),
+        "eight".value(8),
+        "four".value(4),
+        "five".value(5),
+        "nine".value(9),
+        "six".value(6),
+        "two".value(2),
+        "ten".value(10),
+        "one".value(1),
+    ))
+    .context(Expected::Number.context())
+    .parse_next(input)
+}
+
+fn weekday(input: &mut &str) -> PResult<Weekday> {
+    alt((
+        alt(("monday", "mon.", "mon")).value(Weekday::Mon),
+        alt(("tuesday", "tues.", "tues", "tue.", "tue")).value(Weekday::Tue),
+        alt(("wednesday", "wed.", "wed")).value(Weekday::Wed),
+        alt(("thursday", "thurs.", "thurs", "thu.", "thu")).value(Weekday::Thu),
+        alt(("friday", "fri.", "fri")).value(Weekday::Fri),
+        alt(("saturday", "sat.", "sat")).value(Weekday::Sat),
+        alt(("sunday", "sun.", "sun")).value(Weekday::Sun),
+    ))
+    .context(Expected::Weekday.context())
+    .parse_next(input)
+}
+
+fn month(input: &mut &str) -> PResult<Month> {
+    alt((
+        alt(("january", "jan.", "jan")).value(Month::January),
+        alt(("february", "feb.", "feb")).value(Month::February),
+        alt(("march", "mar.", "mar")).value(Month::March),
+        alt(("april", "apr.", "apr")).value(Month::April),
+        "may".value(Month::May),
+        alt(("june", "jun.", "jun")).value(Month::June),
+        alt(("july", "jul.", "jul")).value(Month::July),
+        alt(("august", "aug.", "aug")).value(Month::August),
+        alt(("september", "sept.", "sept", "sep.", "sep")).value(Month::September),
+        alt(("october", "oct.", "oct")).value(Month::October),
+        alt(("november", "nov.", "nov")).value(Month::November),
+        alt(("december", "dec.", "dec")).value(Month::December),
+    ))
+    .context(Expected::Month.context())
+    .parse_next(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        locales::Locale, parse, DayPeriod, HumanDateExpr, HumanDateKeyword, HumanTime, Ordinal,
+        TimeUnit, Year,
+    };
+    use chrono::{Month, NaiveTime, Weekday};
+    use winnow::Parser;
+
+    use super::{
+        month, next, number, ordinal, this, weekday, HumanDateParserEnglishParser, Period,
+    };
+
+    #[test]
+    fn test_keywords() {
+        let mut parser = HumanDateParserEnglishParser::new();
+        assert_eq!(
+            parser.parse_peek("today"),
+            Ok(("", HumanDateExpr::Keyword(HumanDateKeyword::Today)))
+        );
+        assert_eq!(
+            parser.parse_peek("Today"),
+            Ok(("", HumanDateExpr::Keyword(HumanDateKeyword::Today)))
+        );
+        assert_eq!(
+            parser.parse_peek("tomorrow"),
+            Ok(("", HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow)))
+        );
+        assert_eq!(
+            parser.parse_peek("the day after tomorrow"),
+            Ok(("", HumanDateExpr::Keyword(HumanDateKeyword::AfterTomorrow)))
+        );
+        assert_eq!(
+            parser.parse_peek("day after tomorrow"),
+            Ok(("", HumanDateExpr::Keyword(HumanDateKeyword::AfterTomorrow)))
+        );
+        assert_eq!(
+            parser.parse_peek("yesterday"),
+            Ok(("", HumanDateExpr::Keyword(HumanDateKeyword::Yesterday)))
+        );
+        assert_eq!(
+            parser.parse_peek("the day before yesterday"),
+            Ok((
+                "",
+                HumanDateExpr::Keyword(HumanDateKeyword::BeforeYesterday)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_in_n_days() {
+        let mut parser = HumanDateParserEnglishParser::new();
+        assert_eq!(
+            parser.parse_peek("in 3 days"),
+            Ok(("", HumanDateExpr::InN(3, TimeUnit::Day)))
+        );
+        assert_eq!(
+            parser.parse_peek("in three days"),
+            Ok(("", HumanDateExpr::InN(3, TimeUnit::Day)))
+        );
+        assert_eq!(
+            parser.parse_peek("in one day"),
+            Ok(("", HumanDateExpr::InN(1, TimeUnit::Day)))
+        );
+    }
+
+    #[test]
+    fn test_n_days_ago() {
+        let mut parser = HumanDateParserEnglishParser::new();
+        assert_eq!(
+            parser.parse_peek("3 days ago"),
+            Ok(("", HumanDateExpr::NAgo(3, TimeUnit::Day)))
+        );
+        assert_eq!(
+            parser.parse_peek("one day ago"),
+            Ok(("", HumanDateExpr::NAgo(1, TimeUnit::Day)))
+        );
+    }
+
+    #[test]
+    fn test_in_n_units() {
+        let mut parser = HumanDateParserEnglishParser::new();
+        assert_eq!(
+            parser.parse_peek("in 2 weeks"),
+            Ok(("", HumanDateExpr::InN(2, TimeUnit::Week)))
+        );
+        assert_eq!(
+            parser.parse_peek("in a month"),
+            Ok(("", HumanDateExpr::InN(1, TimeUnit::Month)))
+        );
+        assert_eq!(
+            parser.parse_peek("in six months"),
+            Ok(("", HumanDateExpr::InN(6, TimeUnit::Month)))
+        );
+        assert_eq!(
+            parser.parse_peek("a year ago"),
+            Ok(("", HumanDateExpr::NAgo(1, TimeUnit::Year)))
+        );
+        assert_eq!(
+            parser.parse_peek("3 weeks ago"),
+            Ok(("", HumanDateExpr::NAgo(3, TimeUnit::Week)))
+        );
+    }
+
+    #[test]
+    fn test_this_week_weekday() {
+        let mut parser = HumanDateParserEnglishParser::new();
+        assert_eq!(
+            parser.parse_peek("this friday"),
+            Ok(("", HumanDateExpr::ThisWeekWeekday(Weekday::Fri)))
+        );
+        assert_eq!(
+            parser.parse_peek("This Friday"),
+            Ok(("", HumanDateExpr::ThisWeekWeekday(Weekday::Fri)))
+        );
+        assert_eq!(
+            parser.parse_peek("wednesday"),
+            Ok(("", HumanDateExpr::ThisWeekWeekday(Weekday::Wed)))
+        );
+    }
+
+    #[test]
+    fn test_next_week_weekday() {
+        let mut parser = HumanDateParserEnglishParser::new();
+        assert_eq!(
+            parser.parse_peek("next monday"),
+            Ok(("", HumanDateExpr::NextWeekWeekday(Weekday::Mon)))
+        );
+        assert_eq!(
+            parser.parse_peek("next Sat"),
+            Ok(("", HumanDateExpr::NextWeekWeekday(Weekday::Sat)))
+        );
+    }
+
+    #[test]
+    fn test_ordinal_weekday_of_month() {
+        let mut parser = HumanDateParserEnglishParser::new();
+        assert_eq!(
+            parser.parse_peek("the second Sunday of October"),
+            Ok((
+                "",
+                HumanDateExpr::OrdinalWeekdayOfMonth(
+                    Ordinal::Second,
+                    Weekday::Sun,
+                    Month::October,
+                    None
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("1st mon of sept."),
+            Ok((
+                "",
+                HumanDateExpr::OrdinalWeekdayOfMonth(
+                    Ordinal::First,
+                    Weekday::Mon,
+                    Month::September,
+                    None
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("third friday of may"),
+            Ok((
+                "",
+                HumanDateExpr::OrdinalWeekdayOfMonth(
+                    Ordinal::Third,
+                    Weekday::Fri,
+                    Month::May,
+                    None
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_last_weekday_of_month() {
+        let mut parser = HumanDateParserEnglishParser::new();
+        assert_eq!(
+            parser.parse_peek("the last friday of october"),
+            Ok((
+                "",
+                HumanDateExpr::OrdinalWeekdayOfMonth(
+                    Ordinal::Last,
+                    Weekday::Fri,
+                    Month::October,
+                    None
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("second to last monday of may"),
+            Ok((
+                "",
+                HumanDateExpr::OrdinalWeekdayOfMonth(
+                    Ordinal::SecondToLast,
+                    Weekday::Mon,
+                    Month::May,
+                    None
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("the penultimate sunday of june"),
+            Ok((
+                "",
+                HumanDateExpr::OrdinalWeekdayOfMonth(
+                    Ordinal::SecondToLast,
+                    Weekday::Sun,
+                    Month::June,
+                    None
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_ordinal_weekday_of_month_with_year() {
+        let mut parser = HumanDateParserEnglishParser::new();
+        assert_eq!(
+            parser.parse_peek("the second sunday of october 2026"),
+            Ok((
+                "",
+                HumanDateExpr::OrdinalWeekdayOfMonth(
+                    Ordinal::Second,
+                    Weekday::Sun,
+                    Month::October,
+                    Some(Year::Absolute(2026))
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("the first monday of may, 2027"),
+            Ok((
+                "",
+                HumanDateExpr::OrdinalWeekdayOfMonth(
+                    Ordinal::First,
+                    Weekday::Mon,
+                    Month::May,
+                    Some(Year::Absolute(2027))
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("the first monday of may next year"),
+            Ok((
+                "",
+                HumanDateExpr::OrdinalWeekdayOfMonth(
+                    Ordinal::First,
+                    Weekday::Mon,
+                    Month::May,
+                    Some(Year::Relative(1))
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("third friday of june of last year"),
+            Ok((
+                "",
+                HumanDateExpr::OrdinalWeekdayOfMonth(
+                    Ordinal::Third,
+                    Weekday::Fri,
+                    Month::June,
+                    Some(Year::Relative(-1))
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("the first monday of october 10:30"),
+            Ok((
+                "",
+                HumanDateExpr::DateTime(
+                    Box::new(HumanDateExpr::OrdinalWeekdayOfMonth(
+                        Ordinal::First,
+                        Weekday::Mon,
+                        Month::October,
+                        None
+                    )),
+                    HumanTime::At(NaiveTime::from_hms_opt(10, 30, 0).unwrap())
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_absolute() {
+        let mut parser = HumanDateParserEnglishParser::new();
+        assert_eq!(
+            parser.parse_peek("August 15"),
+            Ok((
+                "",
+                HumanDateExpr::Absolute {
+                    day: 15,
+                    month: Some(Month::August),
+                    year: None
+                }
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("Aug 15th, 2025"),
+            Ok((
+                "",
+                HumanDateExpr::Absolute {
+                    day: 15,
+                    month: Some(Month::August),
+                    year: Some(2025)
+                }
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("the 15th of August"),
+            Ok((
+                "",
+                HumanDateExpr::Absolute {
+                    day: 15,
+                    month: Some(Month::August),
+                    year: None
+                }
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("the 1st"),
+            Ok((
+                "",
+                HumanDateExpr::Absolute {
+                    day: 1,
+                    month: None,
+                    year: None
+                }
+            ))
+        );
+        for (input, day) in [
+            ("the 2nd", 2),
+            ("the 3rd", 3),
+            ("the 11th", 11),
+            ("the 22nd", 22),
+        ] {
+            assert_eq!(
+                parser.parse_peek(input),
+                Ok((
+                    "",
+                    HumanDateExpr::Absolute {
+                        day,
+                        month: None,
+                        year: None
+                    }
+                ))
+            );
+        }
+        for input in ["the 1th", "the 2st", "the 11st", "the 13rd"] {
+            assert!(parser.parse_peek(input).is_err(), "{}", input);
+        }
+        assert_eq!(
+            parser.parse_peek("08/15/2024"),
+            Ok((
+                "",
+                HumanDateExpr::Absolute {
+                    day: 15,
+                    month: Some(Month::August),
+                    year: Some(2024)
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_relative_period() {
+        let mut parser = HumanDateParserEnglishParser::new();
+        assert_eq!(
+            parser.parse_peek("this week"),
+            Ok(("", HumanDateExpr::RelativePeriod(Period::Week, 0)))
+        );
+        assert_eq!(
+            parser.parse_peek("next week"),
+            Ok(("", HumanDateExpr::RelativePeriod(Period::Week, 1)))
+        );
+        assert_eq!(
+            parser.parse_peek("last month"),
+            Ok(("", HumanDateExpr::RelativePeriod(Period::Month, -1)))
+        );
+        assert_eq!(
+            parser.parse_peek("this weekend"),
+            Ok(("", HumanDateExpr::RelativePeriod(Period::Weekend, 0)))
+        );
+        assert_eq!(
+            parser.parse_peek("the weekend"),
+            Ok(("", HumanDateExpr::RelativePeriod(Period::Weekend, 0)))
+        );
+        assert_eq!(
+            parser.parse_peek("next year"),
+            Ok(("", HumanDateExpr::RelativePeriod(Period::Year, 1)))
+        );
+    }
+
+    #[test]
+    fn test_range() {
+        let mut parser = HumanDateParserEnglishParser::new();
+        assert_eq!(
+            parser.parse_peek("monday through friday"),
+            Ok((
+                "",
+                HumanDateExpr::Range(
+                    Box::new(HumanDateExpr::ThisWeekWeekday(Weekday::Mon)),
+                    Box::new(HumanDateExpr::ThisWeekWeekday(Weekday::Fri))
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("from monday to friday"),
+            Ok((
+                "",
+                HumanDateExpr::Range(
+                    Box::new(HumanDateExpr::ThisWeekWeekday(Weekday::Mon)),
+                    Box::new(HumanDateExpr::ThisWeekWeekday(Weekday::Fri))
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("between may 10 and 15"),
+            Ok((
+                "",
+                HumanDateExpr::Range(
+                    Box::new(HumanDateExpr::Absolute {
+                        day: 10,
+                        month: Some(Month::May),
+                        year: None
+                    }),
+                    Box::new(HumanDateExpr::Absolute {
+                        day: 15,
+                        month: Some(Month::May),
+                        year: None
+                    })
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("from the 10th to the 15th of may"),
+            Ok((
+                "",
+                HumanDateExpr::Range(
+                    Box::new(HumanDateExpr::Absolute {
+                        day: 10,
+                        month: Some(Month::May),
+                        year: None
+                    }),
+                    Box::new(HumanDateExpr::Absolute {
+                        day: 15,
+                        month: Some(Month::May),
+                        year: None
+                    })
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("from today until friday"),
+            Ok((
+                "",
+                HumanDateExpr::Range(
+                    Box::new(HumanDateExpr::Keyword(HumanDateKeyword::Today)),
+                    Box::new(HumanDateExpr::ThisWeekWeekday(Weekday::Fri))
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_time() {
+        let mut parser = HumanDateParserEnglishParser::new();
+        let at = |hour, minute| HumanTime::At(NaiveTime::from_hms_opt(hour, minute, 0).unwrap());
+        assert_eq!(
+            parser.parse_peek("at 3pm"),
+            Ok(("", HumanDateExpr::Time(at(15, 0))))
+        );
+        assert_eq!(
+            parser.parse_peek("3:30 p.m."),
+            Ok(("", HumanDateExpr::Time(at(15, 30))))
+        );
+        assert_eq!(
+            parser.parse_peek("12am"),
+            Ok(("", HumanDateExpr::Time(at(0, 0))))
+        );
+        assert_eq!(
+            parser.parse_peek("at 15:30"),
+            Ok(("", HumanDateExpr::Time(at(15, 30))))
+        );
+        assert_eq!(
+            parser.parse_peek("at noon"),
+            Ok(("", HumanDateExpr::Time(at(12, 0))))
+        );
+        assert_eq!(
+            parser.parse_peek("in the morning"),
+            Ok((
+                "",
+                HumanDateExpr::Time(HumanTime::Period(DayPeriod::Morning))
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("tonight"),
+            Ok((
+                "",
+                HumanDateExpr::Time(HumanTime::Period(DayPeriod::Evening))
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("tomorrow at 10am"),
+            Ok((
+                "",
+                HumanDateExpr::DateTime(
+                    Box::new(HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow)),
+                    at(10, 0)
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("next friday evening"),
+            Ok((
+                "",
+                HumanDateExpr::DateTime(
+                    Box::new(HumanDateExpr::NextWeekWeekday(Weekday::Fri)),
+                    HumanTime::Period(DayPeriod::Evening)
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("may 3 10am"),
+            Ok((
+                "",
+                HumanDateExpr::DateTime(
+                    Box::new(HumanDateExpr::Absolute {
+                        day: 3,
+                        month: Some(Month::May),
+                        year: None
+                    }),
+                    at(10, 0)
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_weekday() {
+        assert_eq!(weekday.parse_peek("monday"), Ok(("", Weekday::Mon)));
+        assert_eq!(weekday.parse_peek("mon."), Ok(("", Weekday::Mon)));
+        assert_eq!(weekday.parse_peek("tuesday"), Ok(("", Weekday::Tue)));
+        assert_eq!(weekday.parse_peek("tues"), Ok(("", Weekday::Tue)));
+        assert_eq!(weekday.parse_peek("tue"), Ok(("", Weekday::Tue)));
+        assert_eq!(weekday.parse_peek("wednesday"), Ok(("", Weekday::Wed)));
+        assert_eq!(weekday.parse_peek("wed"), Ok(("", Weekday::Wed)));
+        assert_eq!(weekday.parse_peek("thursday"), Ok(("", Weekday::Thu)));
+        assert_eq!(weekday.parse_peek("thurs."), Ok(("", Weekday::Thu)));
+        assert_eq!(weekday.parse_peek("thu"), Ok(("", Weekday::Thu)));
+        assert_eq!(weekday.parse_peek("friday"), Ok(("", Weekday::Fri)));
+        assert_eq!(weekday.parse_peek("fri"), Ok(("", Weekday::Fri)));
+        assert_eq!(weekday.parse_peek("saturday"), Ok(("", Weekday::Sat)));
+        assert_eq!(weekday.parse_peek("sat."), Ok(("", Weekday::Sat)));
+        assert_eq!(weekday.parse_peek("sunday"), Ok(("", Weekday::Sun)));
+        assert_eq!(weekday.parse_peek("sun"), Ok(("", Weekday::Sun)));
+        for (input, expected) in [
+            ("Mon.", Weekday::Mon),
+            ("Saturday", Weekday::Sat),
+            ("SUNDAY", Weekday::Sun),
+        ] {
+            assert_eq!(
+                parse(&mut &*input, &Locale::English),
+                Ok(HumanDateExpr::ThisWeekWeekday(expected))
+            );
+        }
+    }
+
+    #[test]
+    fn test_month() {
+        assert_eq!(month.parse_peek("january"), Ok(("", Month::January)));
+        assert_eq!(month.parse_peek("feb."), Ok(("", Month::February)));
+        assert_eq!(month.parse_peek("may"), Ok(("", Month::May)));
+        assert_eq!(month.parse_peek("sep"), Ok(("", Month::September)));
+        assert_eq!(month.parse_peek("december"), Ok(("", Month::December)));
+        for (input, month) in [
+            ("January 1", Month::January),
+            ("December 1", Month::December),
+        ] {
+            assert_eq!(
+                parse(&mut &*input, &Locale::English),
+                Ok(HumanDateExpr::Absolute {
+                    day: 1,
+                    month: Some(month),
+                    year: None
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_this() {
+        assert_eq!(this.parse_peek("this"), Ok(("", ())));
+        assert_eq!(
+            parse(&mut "This friday", &Locale::English),
+            Ok(HumanDateExpr::ThisWeekWeekday(Weekday::Fri))
+        );
+    }
+
+    #[test]
+    fn test_next() {
+        assert_eq!(next.parse_peek("next"), Ok(("", ())));
+        assert_eq!(
+            parse(&mut "Next friday", &Locale::English),
+            Ok(HumanDateExpr::NextWeekWeekday(Weekday::Fri))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(ordinal.parse_peek("first"), Ok(("", Ordinal::First)));
+        assert_eq!(ordinal.parse_peek("1st"), Ok(("", Ordinal::First)));
+        assert_eq!(ordinal.parse_peek("2nd"), Ok(("", Ordinal::Second)));
+        assert_eq!(ordinal.parse_peek("3rd"), Ok(("", Ordinal::Third)));
+        assert_eq!(ordinal.parse_peek("4th"), Ok(("", Ordinal::Fourth)));
+        assert_eq!(ordinal.parse_peek("fifth"), Ok(("", Ordinal::Fifth)));
+    }
+
+    #[test]
+    fn test_number() {
+        assert_eq!(number(&mut "1"), Ok(1));
+        assert_eq!(number(&mut "01"), Ok(1));
+        assert_eq!(number(&mut "one"), Ok(1));
+        assert_eq!(number(&mut "two"), Ok(2));
+        assert_eq!(number(&mut "three"), Ok(3));
+        assert_eq!(number(&mut "four"), Ok(4));
+        assert_eq!(number(&mut "five"), Ok(5));
+        assert_eq!(number(&mut "six"), Ok(6));
+        assert_eq!(number(&mut "seven"), Ok(7));
+        assert_eq!(number(&mut "eight"), Ok(8));
+        assert_eq!(number(&mut "nine"), Ok(9));
+        assert_eq!(number(&mut "ten"), Ok(10));
+        assert_eq!(number(&mut "eleven"), Ok(11));
+        assert_eq!(number(&mut "twelve"), Ok(12));
+        assert_eq!(number(&mut "thirteen"), Ok(13));
+        assert_eq!(number(&mut "fourteen"), Ok(14));
+        assert_eq!(number(&mut "fifteen"), Ok(15));
+        assert_eq!(number(&mut "sixteen"), Ok(16));
+        assert_eq!(number(&mut "seventeen"), Ok(17));
+        assert_eq!(number(&mut "eighteen"), Ok(18));
+        assert_eq!(number(&mut "nineteen"), Ok(19));
+        assert_eq!(number(&mut "twenty"), Ok(20));
+    }
+}
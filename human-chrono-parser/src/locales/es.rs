@@ -0,0 +1,1007 @@
+use std::str::FromStr;
+
+use chrono::{Month, NaiveTime, Weekday};
+use winnow::{
+    ascii::{digit1, space1},
+    combinator::{alt, opt, preceded},
+    Parser,
+};
+
+use super::range::{range, Connectives};
+use super::{parse_folded, PResult};
+use crate::error::GrammarError;
+
+use super::iberian::{next, numeric_date};
+use super::numeric::{clock_time, day_of_month, hour, whole_hour, year, NOON};
+use crate::{
+    DayPeriod, Expected, HumanDateExpr, HumanDateKeyword, HumanTime, Ordinal, Period, TimeUnit,
+    Year,
+};
+
+/// Words of this grammar, to suggest corrections for misspelled input.
+pub(crate) const VOCABULARY: &[&str] = &[
+    "hoy",
+    "mañana",
+    "pasado",
+    "ayer",
+    "anteayer",
+    "antes",
+    "antier",
+    "de",
+    "dentro",
+    "en",
+    "hace",
+    "día",
+    "días",
+    "semana",
+    "semanas",
+    "mes",
+    "meses",
+    "año",
+    "años",
+    "el",
+    "esta",
+    "este",
+    "esa",
+    "ese",
+    "próximo",
+    "próxima",
+    "próx",
+    "que",
+    "viene",
+    "primer",
+    "primero",
+    "primera",
+    "segundo",
+    "segunda",
+    "tercer",
+    "tercero",
+    "tercera",
+    "cuarto",
+    "cuarta",
+    "quinto",
+    "quinta",
+    "lunes",
+    "martes",
+    "miércoles",
+    "jueves",
+    "viernes",
+    "sábado",
+    "domingo",
+    "lun",
+    "mar",
+    "mié",
+    "jue",
+    "vie",
+    "sáb",
+    "dom",
+    "enero",
+    "febrero",
+    "marzo",
+    "abril",
+    "mayo",
+    "junio",
+    "julio",
+    "agosto",
+    "septiembre",
+    "setiembre",
+    "octubre",
+    "noviembre",
+    "diciembre",
+    "ene",
+    "feb",
+    "abr",
+    "may",
+    "jun",
+    "jul",
+    "ago",
+    "sep",
+    "sept",
+    "set",
+    "oct",
+    "nov",
+    "dic",
+    "a",
+    "las",
+    "la",
+    "por",
+    "cada",
+    "mediodía",
+    "medianoche",
+    "tarde",
+    "noche",
+    "uno",
+    "una",
+    "un",
+    "dos",
+    "tres",
+    "cuatro",
+    "cinco",
+    "seis",
+    "siete",
+    "ocho",
+    "nueve",
+    "diez",
+    "once",
+    "doce",
+    "trece",
+    "catorce",
+    "quince",
+    "dieciséis",
+    "diecisiete",
+    "dieciocho",
+    "diecinueve",
+    "veinte",
+    "del",
+    "siguiente",
+    "último",
+    "última",
+    "penúltimo",
+    "penúltima",
+    "desde",
+    "hasta",
+    "al",
+    "entre",
+    "y",
+    "fin",
+    "pasada",
+];
+
+#[derive(Default)]
+pub struct HumanDateParserSpanishParser;
+
+impl HumanDateParserSpanishParser {
+    pub fn new() -> Self {
+        HumanDateParserSpanishParser {}
+    }
+}
+
+impl Parser<&str, HumanDateExpr, GrammarError> for HumanDateParserSpanishParser {
+    fn parse_next(&mut self, input: &mut &str) -> PResult<HumanDateExpr> {
+        parse_folded(input, grammar)
+    }
+}
+
+/// The grammar, which only matches folded text.
+pub(crate) fn grammar(input: &mut &str) -> PResult<HumanDateExpr> {
+    alt((
+        range(date, range_day, &CONNECTIVES),
+        "esta manana".value(HumanDateExpr::DateTime(
+            Box::new(HumanDateExpr::Keyword(HumanDateKeyword::Today)),
+            HumanTime::Period(DayPeriod::Morning),
+        )),
+        (date, opt(preceded(space1, time))).map(|(date, time)| date.with_time(time)),
+        time.map(HumanDateExpr::Time),
+    ))
+    .parse_next(input)
+}
+
+fn date(input: &mut &str) -> PResult<HumanDateExpr> {
+    alt((
+        keyword.map(HumanDateExpr::Keyword),
+        in_n.map(|(n, unit)| HumanDateExpr::InN(n, unit)),
+        n_ago.map(|(n, unit)| HumanDateExpr::NAgo(n, unit)),
+        absolute.map(|(day, month, year)| HumanDateExpr::Absolute { day, month, year }),
+        ordinal_weekday_of_month.map(|(ordinal, weekday, month, year)| {
+            HumanDateExpr::OrdinalWeekdayOfMonth(ordinal, weekday, month, year)
+        }),
+        relative_period.map(|(period, n)| HumanDateExpr::RelativePeriod(period, n)),
+        next_week_weekday.map(HumanDateExpr::NextWeekWeekday),
+        this_week_weekday.map(HumanDateExpr::ThisWeekWeekday),
+    ))
+    .parse_next(input)
+}
+
+/// "de lunes a viernes", "del 10 al 15 de mayo", "entre el 10 y el 15 de mayo",
+/// "lunes hasta viernes"...
+const CONNECTIVES: Connectives = Connectives {
+    from: &["desde", "del", "de"],
+    to: &["hasta", "al", "a"],
+    between: "entre",
+    and: "y",
+    until: &["hasta"],
+};
+
+/// The day a range bound can be on its own, like "el 15".
+fn range_day(input: &mut &str) -> PResult<u32> {
+    preceded(opt(("el", space1)), day_of_month).parse_next(input)
+}
+
+/// "mañana" is both "tomorrow" and "morning". These are the phrases where it
+/// means "morning" and must not be read as a date.
+pub(crate) fn morning(input: &mut &str) -> PResult<()> {
+    alt(("la manana", "las mananas", "cada manana"))
+        .void()
+        .parse_next(input)
+}
+
+fn keyword(input: &mut &str) -> PResult<HumanDateKeyword> {
+    alt((
+        "hoy".value(HumanDateKeyword::Today),
+        "pasado manana".value(HumanDateKeyword::AfterTomorrow),
+        "manana".value(HumanDateKeyword::Tomorrow),
+        alt(("anteayer", "antes de ayer", "antier")).value(HumanDateKeyword::BeforeYesterday),
+        "ayer".value(HumanDateKeyword::Yesterday),
+    ))
+    .context(Expected::Keyword.context())
+    .parse_next(input)
+}
+
+fn in_n(input: &mut &str) -> PResult<(u64, TimeUnit)> {
+    preceded((alt(("dentro de", "en")), space1), quantity).parse_next(input)
+}
+
+fn n_ago(input: &mut &str) -> PResult<(u64, TimeUnit)> {
+    preceded(("hace", space1), quantity).parse_next(input)
+}
+
+fn quantity(input: &mut &str) -> PResult<(u64, TimeUnit)> {
+    let (n, _, unit) = (number, space1, unit).parse_next(input)?;
+    Ok((n, unit))
+}
+
+fn unit(input: &mut &str) -> PResult<TimeUnit> {
+    alt((
+        alt(("dias", "dia")).value(TimeUnit::Day),
+        alt(("semanas", "semana")).value(TimeUnit::Week),
+        alt(("meses", "mes")).value(TimeUnit::Month),
+        alt(("anos", "ano")).value(TimeUnit::Year),
+    ))
+    .context(Expected::Unit.context())
+    .parse_next(input)
+}
+
+/// "esta semana", "el mes que viene", "el próximo fin de semana", "el año pasado"...
+fn relative_period(input: &mut &str) -> PResult<(Period, i32)> {
+    preceded(
+        opt((alt(("el", "la")), space1)),
+        alt((
+            preceded((this, space1), period).map(|period| (period, 0)),
+            preceded((next, space1), period).map(|period| (period, 1)),
+            (
+                period,
+                space1,
+                alt((
+                    alt(("que viene", "siguiente", "proximo", "proxima")).value(1),
+                    alt(("pasado", "pasada")).value(-1),
+                )),
+            )
+                .map(|(period, _, n)| (period, n)),
+            "fin de semana".value((Period::Weekend, 0)),
+        )),
+    )
+    .parse_next(input)
+}
+
+fn period(input: &mut &str) -> PResult<Period> {
+    alt((
+        "fin de semana".value(Period::Weekend),
+        "semana".value(Period::Week),
+        "mes".value(Period::Month),
+        "ano".value(Period::Year),
+    ))
+    .parse_next(input)
+}
+
+fn this_week_weekday(input: &mut &str) -> PResult<Weekday> {
+    preceded(
+        opt(("el", space1)),
+        alt((
+            preceded((this, space1), weekday),
+            // On their own, these are more often words than weekdays: "mar azul".
+            weekday
+                .with_taken()
+                .verify(|(_, taken)| !["mar", "mie", "dom"].contains(taken))
+                .map(|(weekday, _)| weekday),
+        )),
+    )
+    .parse_next(input)
+}
+
+fn next_week_weekday(input: &mut &str) -> PResult<Weekday> {
+    let (_, weekday) = (
+        opt(("el", space1)),
+        alt((
+            (next, space1, weekday).map(|(_, _, weekday)| weekday),
+            (weekday, space1, alt(("que viene", "proximo"))).map(|(weekday, _, _)| weekday),
+        )),
+    )
+        .parse_next(input)?;
+    Ok(weekday)
+}
+
+fn ordinal_weekday_of_month(input: &mut &str) -> PResult<(Ordinal, Weekday, Month, Option<Year>)> {
+    let (_, ordinal, _, weekday, _, _, _, month, year) = (
+        opt(("el", space1)),
+        ordinal,
+        space1,
+        weekday,
+        space1,
+        "de",
+        space1,
+        month,
+        opt(preceded(space1, which_year)),
+    )
+        .parse_next(input)?;
+    Ok((ordinal, weekday, month, year))
+}
+
+/// "de 2026", "del año que viene", "de este año"...
+fn which_year(input: &mut &str) -> PResult<Year> {
+    alt((
+        preceded(("de", space1), year).map(Year::Absolute),
+        alt((
+            "del ano que viene".void(),
+            "del ano siguiente".void(),
+            ("del", space1, next, space1, "ano").void(),
+        ))
+        .value(Year::Relative(1)),
+        alt(("de este ano", "de ese ano")).value(Year::Relative(0)),
+        "del ano pasado".value(Year::Relative(-1)),
+    ))
+    .parse_next(input)
+}
+
+fn absolute(input: &mut &str) -> PResult<(u32, Option<Month>, Option<i32>)> {
+    preceded(
+        opt(("el", space1)),
+        alt((
+            numeric_date.map(|(day, month, year)| (day, Some(month), year)),
+            (
+                opt(("dia", space1)),
+                day_of_month,
+                space1,
+                "de",
+                space1,
+                month,
+                opt(preceded((space1, "de", space1), year)),
+            )
+                .map(|(_, day, _, _, _, month, year)| (day, Some(month), year)),
+            preceded(("dia", space1), day_of_month).map(|day| (day, None, None)),
+        )),
+    )
+    .parse_next(input)
+}
+
+fn time(input: &mut &str) -> PResult<HumanTime> {
+    alt((
+        preceded((alt(("a las", "a la")), space1), alt((clock, whole_hour))).map(HumanTime::At),
+        clock.map(HumanTime::At),
+        preceded((alt(("por la", "en la", "de la")), space1), day_period).map(HumanTime::Period),
+    ))
+    .context(Expected::Time.context())
+    .parse_next(input)
+}
+
+fn clock(input: &mut &str) -> PResult<NaiveTime> {
+    alt((
+        "mediodia".value(NOON),
+        "medianoche".value(NaiveTime::MIN),
+        (hour, space1, "de la", space1, day_period)
+            .verify_map(|(hour, _, _, _, period)| period.at(hour, 0)),
+        clock_time,
+    ))
+    .parse_next(input)
+}
+
+fn day_period(input: &mut &str) -> PResult<DayPeriod> {
+    alt((
+        "manana".value(DayPeriod::Morning),
+        "tarde".value(DayPeriod::Afternoon),
+        "noche".value(DayPeriod::Evening),
+    ))
+    .parse_next(input)
+}
+
+fn this(input: &mut &str) -> PResult<()> {
+    alt(("esta", "este", "esa", "ese")).void().parse_next(input)
+}
+
+fn ordinal(input: &mut &str) -> PResult<Ordinal> {
+    alt((
+        alt(("primera", "primero", "primer")).value(Ordinal::First),
+        alt(("segunda", "segundo")).value(Ordinal::Second),
+        alt(("tercera", "tercero", "tercer")).value(Ordinal::Third),
+        alt(("cuarta", "cuarto")).value(Ordinal::Fourth),
+        alt(("quinta", "quinto")).value(Ordinal::Fifth),
+        alt(("ultima", "ultimo")).value(Ordinal::Last),
+        alt(("penultima", "penultimo")).value(Ordinal::SecondToLast),
+    ))
+    .parse_next(input)
+}
+
+fn number(input: &mut &str) -> PResult<u64> {
+    alt((
+        digit1.try_map(FromStr::from_str),
+        "diecisiete".value(17),
+        "dieciseis".value(16),
+        "dieciocho".value(18),
+        "diecinueve".value(19),
+        "diez".value(10),
+        "catorce".value(14),
+        "cuatro".value(4),
+        "quince".value(15),
+        "veinte".value(20),
+        "trece".value(13),
+        "tres".value(3),
+        "cinco".value(5),
+        "seis".value(6),
+        "siete".value(7),
+        "ocho".value(8),
+        "nueve".value(9),
+        "once".value(11),
+        "doce".value(12),
+        "dos".value(2),
+        alt(("uno", "una", "un")).value(1),
+    ))
+    .context(Expected::Number.context())
+    .parse_next(input)
+}
+
+fn weekday(input: &mut &str) -> PResult<Weekday> {
+    alt((
+        alt(("lunes", "lun.", "lun")).value(Weekday::Mon),
+        alt(("martes", "mar.", "mar")).value(Weekday::Tue),
+        alt(("miercoles", "mie.", "mie")).value(Weekday::Wed),
+        alt(("jueves", "jue.", "jue")).value(Weekday::Thu),
+        alt(("viernes", "vie.", "vie")).value(Weekday::Fri),
+        alt(("sabado", "sab.", "sab")).value(Weekday::Sat),
+        alt(("domingo", "dom.", "dom")).value(Weekday::Sun),
+    ))
+    .context(Expected::Weekday.context())
+    .parse_next(input)
+}
+
+fn month(input: &mut &str) -> PResult<Month> {
+    alt((
+        alt(("enero", "ene.", "ene")).value(Month::January),
+        alt(("febrero", "feb.", "feb")).value(Month::February),
+        alt(("marzo", "mar.", "mar")).value(Month::March),
+        alt(("abril", "abr.", "abr")).value(Month::April),
+        alt(("mayo", "may.", "may")).value(Month::May),
+        alt(("junio", "jun.", "jun")).value(Month::June),
+        alt(("julio", "jul.", "jul")).value(Month::July),
+        alt(("agosto", "ago.", "ago")).value(Month::August),
+        alt((
+            "septiembre",
+            "setiembre",
+            "sept.",
+            "sept",
+            "sep.",
+            "sep",
+            "set.",
+            "set",
+        ))
+        .value(Month::September),
+        alt(("octubre", "oct.", "oct")).value(Month::October),
+        alt(("noviembre", "nov.", "nov")).value(Month::November),
+        alt(("diciembre", "dic.", "dic")).value(Month::December),
+    ))
+    .context(Expected::Month.context())
+    .parse_next(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        extract_all, locales::Locale, parse, DayPeriod, HumanDateExpr, HumanDateKeyword, HumanTime,
+        Ordinal, TimeUnit, Year,
+    };
+    use chrono::{Month, NaiveTime, Weekday};
+    use winnow::Parser;
+
+    use super::{
+        month, morning, number, ordinal, this, weekday, HumanDateParserSpanishParser, Period,
+    };
+
+    #[test]
+    fn test_keywords() {
+        let mut parser = HumanDateParserSpanishParser::new();
+        assert_eq!(
+            parser.parse_peek("hoy"),
+            Ok(("", HumanDateExpr::Keyword(HumanDateKeyword::Today)))
+        );
+        assert_eq!(
+            parser.parse_peek("mañana"),
+            Ok(("", HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow)))
+        );
+        assert_eq!(
+            parser.parse_peek("pasado mañana"),
+            Ok(("", HumanDateExpr::Keyword(HumanDateKeyword::AfterTomorrow)))
+        );
+        assert_eq!(
+            parser.parse_peek("esta mañana"),
+            Ok((
+                "",
+                HumanDateExpr::DateTime(
+                    Box::new(HumanDateExpr::Keyword(HumanDateKeyword::Today)),
+                    HumanTime::Period(DayPeriod::Morning)
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("ayer"),
+            Ok(("", HumanDateExpr::Keyword(HumanDateKeyword::Yesterday)))
+        );
+        assert_eq!(
+            parser.parse_peek("anteayer"),
+            Ok((
+                "",
+                HumanDateExpr::Keyword(HumanDateKeyword::BeforeYesterday)
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("antes de ayer"),
+            Ok((
+                "",
+                HumanDateExpr::Keyword(HumanDateKeyword::BeforeYesterday)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_n_days_ago() {
+        let mut parser = HumanDateParserSpanishParser::new();
+        assert_eq!(
+            parser.parse_peek("hace 3 días"),
+            Ok(("", HumanDateExpr::NAgo(3, TimeUnit::Day)))
+        );
+        assert_eq!(
+            parser.parse_peek("hace un dia"),
+            Ok(("", HumanDateExpr::NAgo(1, TimeUnit::Day)))
+        );
+    }
+
+    #[test]
+    fn test_in_n_days() {
+        let mut parser = HumanDateParserSpanishParser::new();
+        assert_eq!(
+            parser.parse_peek("dentro de 3 días"),
+            Ok(("", HumanDateExpr::InN(3, TimeUnit::Day)))
+        );
+        assert_eq!(
+            parser.parse_peek("en tres dias"),
+            Ok(("", HumanDateExpr::InN(3, TimeUnit::Day)))
+        );
+        assert_eq!(
+            parser.parse_peek("dentro de un día"),
+            Ok(("", HumanDateExpr::InN(1, TimeUnit::Day)))
+        );
+    }
+
+    #[test]
+    fn test_in_n_units() {
+        let mut parser = HumanDateParserSpanishParser::new();
+        assert_eq!(
+            parser.parse_peek("dentro de 2 semanas"),
+            Ok(("", HumanDateExpr::InN(2, TimeUnit::Week)))
+        );
+        assert_eq!(
+            parser.parse_peek("en seis meses"),
+            Ok(("", HumanDateExpr::InN(6, TimeUnit::Month)))
+        );
+        assert_eq!(
+            parser.parse_peek("dentro de un año"),
+            Ok(("", HumanDateExpr::InN(1, TimeUnit::Year)))
+        );
+        assert_eq!(
+            parser.parse_peek("hace tres meses"),
+            Ok(("", HumanDateExpr::NAgo(3, TimeUnit::Month)))
+        );
+    }
+
+    #[test]
+    fn test_this_week_weekday() {
+        let mut parser = HumanDateParserSpanishParser::new();
+        assert_eq!(
+            parser.parse_peek("este viernes"),
+            Ok(("", HumanDateExpr::ThisWeekWeekday(Weekday::Fri)))
+        );
+        assert_eq!(
+            parser.parse_peek("el martes"),
+            Ok(("", HumanDateExpr::ThisWeekWeekday(Weekday::Tue)))
+        );
+        assert_eq!(
+            parser.parse_peek("miércoles"),
+            Ok(("", HumanDateExpr::ThisWeekWeekday(Weekday::Wed)))
+        );
+    }
+
+    #[test]
+    fn test_next_week_weekday() {
+        let mut parser = HumanDateParserSpanishParser::new();
+        assert_eq!(
+            parser.parse_peek("el próximo lunes"),
+            Ok(("", HumanDateExpr::NextWeekWeekday(Weekday::Mon)))
+        );
+        assert_eq!(
+            parser.parse_peek("el jueves que viene"),
+            Ok(("", HumanDateExpr::NextWeekWeekday(Weekday::Thu)))
+        );
+        assert_eq!(
+            parser.parse_peek("el sábado próximo"),
+            Ok(("", HumanDateExpr::NextWeekWeekday(Weekday::Sat)))
+        );
+    }
+
+    #[test]
+    fn test_ordinal_weekday_of_month() {
+        let mut parser = HumanDateParserSpanishParser::new();
+        assert_eq!(
+            parser.parse_peek("el primer domingo de octubre"),
+            Ok((
+                "",
+                HumanDateExpr::OrdinalWeekdayOfMonth(
+                    Ordinal::First,
+                    Weekday::Sun,
+                    Month::October,
+                    None
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("tercer viernes de mayo"),
+            Ok((
+                "",
+                HumanDateExpr::OrdinalWeekdayOfMonth(
+                    Ordinal::Third,
+                    Weekday::Fri,
+                    Month::May,
+                    None
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_last_weekday_of_month() {
+        let mut parser = HumanDateParserSpanishParser::new();
+        assert_eq!(
+            parser.parse_peek("el ultimo viernes de octubre"),
+            Ok((
+                "",
+                HumanDateExpr::OrdinalWeekdayOfMonth(
+                    Ordinal::Last,
+                    Weekday::Fri,
+                    Month::October,
+                    None
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_ordinal_weekday_of_month_with_year() {
+        let mut parser = HumanDateParserSpanishParser::new();
+        assert_eq!(
+            parser.parse_peek("el primer domingo de octubre de 2026"),
+            Ok((
+                "",
+                HumanDateExpr::OrdinalWeekdayOfMonth(
+                    Ordinal::First,
+                    Weekday::Sun,
+                    Month::October,
+                    Some(Year::Absolute(2026))
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("el segundo lunes de mayo del ano que viene"),
+            Ok((
+                "",
+                HumanDateExpr::OrdinalWeekdayOfMonth(
+                    Ordinal::Second,
+                    Weekday::Mon,
+                    Month::May,
+                    Some(Year::Relative(1))
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("tercer viernes de enero del proximo ano"),
+            Ok((
+                "",
+                HumanDateExpr::OrdinalWeekdayOfMonth(
+                    Ordinal::Third,
+                    Weekday::Fri,
+                    Month::January,
+                    Some(Year::Relative(1))
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_absolute() {
+        let mut parser = HumanDateParserSpanishParser::new();
+        assert_eq!(
+            parser.parse_peek("el 15 de agosto"),
+            Ok((
+                "",
+                HumanDateExpr::Absolute {
+                    day: 15,
+                    month: Some(Month::August),
+                    year: None
+                }
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("15 de agosto de 2025"),
+            Ok((
+                "",
+                HumanDateExpr::Absolute {
+                    day: 15,
+                    month: Some(Month::August),
+                    year: Some(2025)
+                }
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("el día 15"),
+            Ok((
+                "",
+                HumanDateExpr::Absolute {
+                    day: 15,
+                    month: None,
+                    year: None
+                }
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("15/08/2024"),
+            Ok((
+                "",
+                HumanDateExpr::Absolute {
+                    day: 15,
+                    month: Some(Month::August),
+                    year: Some(2024)
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_relative_period() {
+        let mut parser = HumanDateParserSpanishParser::new();
+        assert_eq!(
+            parser.parse_peek("esta semana"),
+            Ok(("", HumanDateExpr::RelativePeriod(Period::Week, 0)))
+        );
+        assert_eq!(
+            parser.parse_peek("la semana que viene"),
+            Ok(("", HumanDateExpr::RelativePeriod(Period::Week, 1)))
+        );
+        assert_eq!(
+            parser.parse_peek("la proxima semana"),
+            Ok(("", HumanDateExpr::RelativePeriod(Period::Week, 1)))
+        );
+        assert_eq!(
+            parser.parse_peek("el mes pasado"),
+            Ok(("", HumanDateExpr::RelativePeriod(Period::Month, -1)))
+        );
+        assert_eq!(
+            parser.parse_peek("este fin de semana"),
+            Ok(("", HumanDateExpr::RelativePeriod(Period::Weekend, 0)))
+        );
+        assert_eq!(
+            parser.parse_peek("el ano que viene"),
+            Ok(("", HumanDateExpr::RelativePeriod(Period::Year, 1)))
+        );
+    }
+
+    #[test]
+    fn test_range() {
+        let mut parser = HumanDateParserSpanishParser::new();
+        assert_eq!(
+            parser.parse_peek("lunes hasta viernes"),
+            Ok((
+                "",
+                HumanDateExpr::Range(
+                    Box::new(HumanDateExpr::ThisWeekWeekday(Weekday::Mon)),
+                    Box::new(HumanDateExpr::ThisWeekWeekday(Weekday::Fri))
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("de lunes a viernes"),
+            Ok((
+                "",
+                HumanDateExpr::Range(
+                    Box::new(HumanDateExpr::ThisWeekWeekday(Weekday::Mon)),
+                    Box::new(HumanDateExpr::ThisWeekWeekday(Weekday::Fri))
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("del 10 al 15 de mayo"),
+            Ok((
+                "",
+                HumanDateExpr::Range(
+                    Box::new(HumanDateExpr::Absolute {
+                        day: 10,
+                        month: Some(Month::May),
+                        year: None
+                    }),
+                    Box::new(HumanDateExpr::Absolute {
+                        day: 15,
+                        month: Some(Month::May),
+                        year: None
+                    })
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("entre el 10 y el 15 de mayo"),
+            Ok((
+                "",
+                HumanDateExpr::Range(
+                    Box::new(HumanDateExpr::Absolute {
+                        day: 10,
+                        month: Some(Month::May),
+                        year: None
+                    }),
+                    Box::new(HumanDateExpr::Absolute {
+                        day: 15,
+                        month: Some(Month::May),
+                        year: None
+                    })
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("desde hoy hasta el viernes"),
+            Ok((
+                "",
+                HumanDateExpr::Range(
+                    Box::new(HumanDateExpr::Keyword(HumanDateKeyword::Today)),
+                    Box::new(HumanDateExpr::ThisWeekWeekday(Weekday::Fri))
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_time() {
+        let mut parser = HumanDateParserSpanishParser::new();
+        let at = |hour, minute| HumanTime::At(NaiveTime::from_hms_opt(hour, minute, 0).unwrap());
+        assert_eq!(
+            parser.parse_peek("a las 10"),
+            Ok(("", HumanDateExpr::Time(at(10, 0))))
+        );
+        assert_eq!(
+            parser.parse_peek("a las 3 de la tarde"),
+            Ok(("", HumanDateExpr::Time(at(15, 0))))
+        );
+        assert_eq!(
+            parser.parse_peek("15:30"),
+            Ok(("", HumanDateExpr::Time(at(15, 30))))
+        );
+        assert_eq!(
+            parser.parse_peek("mediodía"),
+            Ok(("", HumanDateExpr::Time(at(12, 0))))
+        );
+        assert_eq!(
+            parser.parse_peek("por la noche"),
+            Ok((
+                "",
+                HumanDateExpr::Time(HumanTime::Period(DayPeriod::Evening))
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("mañana por la mañana"),
+            Ok((
+                "",
+                HumanDateExpr::DateTime(
+                    Box::new(HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow)),
+                    HumanTime::Period(DayPeriod::Morning)
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("el viernes a las 15:30"),
+            Ok((
+                "",
+                HumanDateExpr::DateTime(
+                    Box::new(HumanDateExpr::ThisWeekWeekday(Weekday::Fri)),
+                    at(15, 30)
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_morning() {
+        assert_eq!(morning.parse_peek("la manana"), Ok(("", ())));
+        assert_eq!(morning.parse_peek("las mananas"), Ok(("", ())));
+        assert_eq!(morning.parse_peek("cada manana"), Ok(("", ())));
+        for input in ["la mañana", "las mañanas", "cada mañana"] {
+            assert_eq!(extract_all(&mut &*input, &Locale::Spanish), vec![]);
+        }
+    }
+
+    #[test]
+    fn test_weekday() {
+        assert_eq!(weekday.parse_peek("lunes"), Ok(("", Weekday::Mon)));
+        assert_eq!(weekday.parse_peek("lun."), Ok(("", Weekday::Mon)));
+        assert_eq!(weekday.parse_peek("martes"), Ok(("", Weekday::Tue)));
+        assert_eq!(weekday.parse_peek("miercoles"), Ok(("", Weekday::Wed)));
+        assert_eq!(weekday.parse_peek("jueves"), Ok(("", Weekday::Thu)));
+        assert_eq!(weekday.parse_peek("viernes"), Ok(("", Weekday::Fri)));
+        assert_eq!(weekday.parse_peek("sabado"), Ok(("", Weekday::Sat)));
+        assert_eq!(weekday.parse_peek("sab"), Ok(("", Weekday::Sat)));
+        assert_eq!(weekday.parse_peek("domingo"), Ok(("", Weekday::Sun)));
+        for (input, expected) in [("miércoles", Weekday::Wed), ("sábado", Weekday::Sat)] {
+            assert_eq!(
+                parse(&mut &*input, &Locale::Spanish),
+                Ok(HumanDateExpr::ThisWeekWeekday(expected))
+            );
+        }
+    }
+
+    #[test]
+    fn test_month() {
+        assert_eq!(month.parse_peek("enero"), Ok(("", Month::January)));
+        assert_eq!(month.parse_peek("marzo"), Ok(("", Month::March)));
+        assert_eq!(month.parse_peek("septiembre"), Ok(("", Month::September)));
+        assert_eq!(month.parse_peek("setiembre"), Ok(("", Month::September)));
+        assert_eq!(month.parse_peek("dic."), Ok(("", Month::December)));
+    }
+
+    #[test]
+    fn test_this() {
+        assert_eq!(this.parse_peek("esta"), Ok(("", ())));
+        assert_eq!(this.parse_peek("este"), Ok(("", ())));
+        assert_eq!(this.parse_peek("esa"), Ok(("", ())));
+        assert_eq!(this.parse_peek("ese"), Ok(("", ())));
+        assert!(this.parse_peek("essa").is_err());
+        assert!(this.parse_peek("esse").is_err());
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(ordinal.parse_peek("primer"), Ok(("", Ordinal::First)));
+        assert_eq!(ordinal.parse_peek("primera"), Ok(("", Ordinal::First)));
+        assert_eq!(ordinal.parse_peek("segundo"), Ok(("", Ordinal::Second)));
+        assert_eq!(ordinal.parse_peek("tercer"), Ok(("", Ordinal::Third)));
+        assert_eq!(ordinal.parse_peek("cuarta"), Ok(("", Ordinal::Fourth)));
+        assert_eq!(ordinal.parse_peek("quinto"), Ok(("", Ordinal::Fifth)));
+        assert_eq!(ordinal.parse_peek("ultima"), Ok(("", Ordinal::Last)));
+        assert_eq!(
+            ordinal.parse_peek("penultimo"),
+            Ok(("", Ordinal::SecondToLast))
+        );
+        assert!(ordinal.parse_peek("primeira").is_err());
+        assert!(ordinal.parse_peek("quarta").is_err());
+    }
+
+    #[test]
+    fn test_number() {
+        assert_eq!(number(&mut "1"), Ok(1));
+        assert_eq!(number(&mut "uno"), Ok(1));
+        assert_eq!(number(&mut "una"), Ok(1));
+        assert_eq!(number(&mut "un"), Ok(1));
+        assert_eq!(number(&mut "dos"), Ok(2));
+        assert_eq!(number(&mut "tres"), Ok(3));
+        assert_eq!(number(&mut "cuatro"), Ok(4));
+        assert_eq!(number(&mut "cinco"), Ok(5));
+        assert_eq!(number(&mut "seis"), Ok(6));
+        assert_eq!(number(&mut "siete"), Ok(7));
+        assert_eq!(number(&mut "ocho"), Ok(8));
+        assert_eq!(number(&mut "nueve"), Ok(9));
+        assert_eq!(
+            parse(&mut "dentro de dieciséis días", &Locale::Spanish),
+            Ok(HumanDateExpr::InN(16, TimeUnit::Day))
+        );
+        assert_eq!(number(&mut "diez"), Ok(10));
+        assert_eq!(number(&mut "once"), Ok(11));
+        assert_eq!(number(&mut "doce"), Ok(12));
+        assert_eq!(number(&mut "trece"), Ok(13));
+        assert_eq!(number(&mut "catorce"), Ok(14));
+        assert_eq!(number(&mut "quince"), Ok(15));
+        assert_eq!(number(&mut "dieciseis"), Ok(16));
+        assert_eq!(number(&mut "diecisiete"), Ok(17));
+        assert_eq!(number(&mut "dieciocho"), Ok(18));
+        assert_eq!(number(&mut "diecinueve"), Ok(19));
+        assert_eq!(number(&mut "veinte"), Ok(20));
+    }
+}
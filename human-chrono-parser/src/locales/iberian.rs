@@ -0,0 +1,74 @@
+//! Combinators shared by the Iberian locales (Portuguese and Spanish).
+//!
+//! Both languages spell "next" the same way and write numeric dates day first,
+//! so these are kept in a single place to avoid the grammars drifting apart.
+//! Words the languages spell differently, like "this" and the ordinals, live in
+//! each locale.
+
+use chrono::Month;
+use winnow::{
+    combinator::{alt, opt, preceded},
+    Parser,
+};
+
+use super::PResult;
+
+use super::numeric::{date_separator, day_of_month, month_number, year};
+
+pub(crate) fn next(input: &mut &str) -> PResult<()> {
+    alt(("proxima", "proximo", "prox.", "prox"))
+        .void()
+        .parse_next(input)
+}
+
+/// Day-first numeric dates: "15/08", "15/08/2024" or "15-08-24".
+pub(crate) fn numeric_date(input: &mut &str) -> PResult<(u32, Month, Option<i32>)> {
+    let (day, separator, month) = (day_of_month, date_separator, month_number).parse_next(input)?;
+    let year = opt(preceded(separator, year)).parse_next(input)?;
+    Ok((day, month, year))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{locales::Locale, parse, HumanDateExpr};
+    use chrono::{Month, Weekday};
+    use winnow::Parser;
+
+    use super::{next, numeric_date};
+
+    #[test]
+    fn test_next() {
+        assert_eq!(next.parse_peek("proxima"), Ok(("", ())));
+        assert_eq!(next.parse_peek("proximo"), Ok(("", ())));
+        assert_eq!(next.parse_peek("prox."), Ok(("", ())));
+        assert_eq!(
+            parse(&mut "próxima sexta", &Locale::BrazilianPortuguese),
+            Ok(HumanDateExpr::NextWeekWeekday(Weekday::Fri))
+        );
+        assert_eq!(
+            parse(&mut "el próximo lunes", &Locale::Spanish),
+            Ok(HumanDateExpr::NextWeekWeekday(Weekday::Mon))
+        );
+    }
+
+    #[test]
+    fn test_numeric_date() {
+        assert_eq!(
+            numeric_date.parse_peek("15/08"),
+            Ok(("", (15, Month::August, None)))
+        );
+        assert_eq!(
+            numeric_date.parse_peek("15/08/2024"),
+            Ok(("", (15, Month::August, Some(2024))))
+        );
+        assert_eq!(
+            numeric_date.parse_peek("15-08-24"),
+            Ok(("", (15, Month::August, Some(2024))))
+        );
+        assert_eq!(
+            numeric_date.parse_peek("15-08/24"),
+            Ok(("/24", (15, Month::August, None)))
+        );
+        assert!(numeric_date.parse_peek("08/15").is_err());
+    }
+}
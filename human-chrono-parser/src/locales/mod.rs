@@ -1,18 +1,112 @@
+use std::sync::Arc;
+
+use chrono::Weekday;
+use en_us::HumanDateParserEnglishParser;
+use es::HumanDateParserSpanishParser;
 use pt_br::HumanDateParserBrazillianPortugueseParser;
-use winnow::{error::ContextError, Parser};
+use pt_pt::HumanDateParserEuropeanPortugueseParser;
+use winnow::{combinator::fail, stream::Offset, Parser};
 
-use crate::HumanDateExpr;
+use crate::{
+    error::GrammarError, normalize::Folded, BrazilianHolidays, HolidayCalendar, HumanDateExpr,
+};
 
+pub mod en_us;
+pub mod es;
+mod iberian;
+mod numeric;
 pub mod pt_br;
+pub mod pt_pt;
+mod range;
+
+// Renamed to `ModalResult` in winnow 0.6.25, which 0.6.18 doesn't have yet.
+#[allow(deprecated)]
+pub(crate) type PResult<O> = winnow::PResult<O, GrammarError>;
 
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Locale {
     BrazilianPortuguese,
+    EuropeanPortuguese,
+    English,
+    Spanish,
 }
 
 impl Locale {
-    pub fn parser(&self) -> Box<dyn Parser<&str, HumanDateExpr, ContextError>> {
+    /// The locale grammar. Like [`crate::parse`], it matches regardless of case
+    /// and accents, but it doesn't need to read the whole input.
+    pub fn parser(&self) -> Box<dyn Parser<&str, HumanDateExpr, GrammarError>> {
         match self {
             Self::BrazilianPortuguese => Box::new(HumanDateParserBrazillianPortugueseParser::new()),
+            Self::EuropeanPortuguese => Box::new(HumanDateParserEuropeanPortugueseParser::new()),
+            Self::English => Box::new(HumanDateParserEnglishParser::new()),
+            Self::Spanish => Box::new(HumanDateParserSpanishParser::new()),
+        }
+    }
+
+    /// The grammar behind [`Locale::parser`], which only matches folded text.
+    pub(crate) fn grammar(&self) -> fn(&mut &str) -> PResult<HumanDateExpr> {
+        match self {
+            Self::BrazilianPortuguese => pt_br::grammar,
+            Self::EuropeanPortuguese => pt_pt::grammar,
+            Self::English => en_us::grammar,
+            Self::Spanish => es::grammar,
+        }
+    }
+
+    /// Phrases that contain a date word without being a date, like the Spanish
+    /// "por la mañana" ("in the morning"), in folded text. `extract_all` skips
+    /// them as a whole instead of matching inside them.
+    pub(crate) fn non_dates(&self) -> Box<dyn Parser<&str, (), GrammarError>> {
+        match self {
+            Self::Spanish => Box::new(es::morning),
+            Self::BrazilianPortuguese | Self::EuropeanPortuguese | Self::English => {
+                Box::new(nothing)
+            }
+        }
+    }
+
+    /// The first day of the week by local convention.
+    pub fn week_start(&self) -> Weekday {
+        match self {
+            Self::BrazilianPortuguese | Self::English => Weekday::Sun,
+            Self::EuropeanPortuguese | Self::Spanish => Weekday::Mon,
         }
     }
+
+    /// The national holidays of the locale's country, where they are built in.
+    pub fn holidays(&self) -> Option<Arc<dyn HolidayCalendar>> {
+        match self {
+            Self::BrazilianPortuguese => Some(Arc::new(BrazilianHolidays)),
+            Self::EuropeanPortuguese | Self::English | Self::Spanish => None,
+        }
+    }
+
+    pub(crate) fn vocabulary(&self) -> Vec<&'static str> {
+        match self {
+            Self::BrazilianPortuguese => pt_br::VOCABULARY.to_vec(),
+            Self::EuropeanPortuguese => [pt_br::VOCABULARY, pt_pt::VOCABULARY].concat(),
+            Self::English => en_us::VOCABULARY.to_vec(),
+            Self::Spanish => es::VOCABULARY.to_vec(),
+        }
+    }
+}
+
+/// Runs `grammar` on the folded `input`, and maps how far it got back to `input`.
+fn parse_folded(
+    input: &mut &str,
+    mut grammar: fn(&mut &str) -> PResult<HumanDateExpr>,
+) -> PResult<HumanDateExpr> {
+    let folded = Folded::new(input);
+    let text = &mut folded.as_str();
+    let start = *text;
+    let source_len = input.len();
+    let expr = grammar
+        .parse_next(text)
+        .map_err(|err| err.map(|err| err.unfold(&folded, source_len)))?;
+    *input = &input[folded.source_offset(text.offset_from(&start))..];
+    Ok(expr)
+}
+
+fn nothing(input: &mut &str) -> PResult<()> {
+    fail.parse_next(input)
 }
@@ -0,0 +1,139 @@
+//! Numeric date components shared by every locale. Only the order in which
+//! they're combined (day/month vs. month/day) is locale specific.
+
+use std::str::FromStr;
+
+use chrono::{Month, NaiveTime};
+use winnow::{
+    stream::AsChar,
+    token::{one_of, take_while},
+    Parser,
+};
+
+use super::PResult;
+
+pub(crate) const NOON: NaiveTime = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+
+pub(crate) fn day_of_month(input: &mut &str) -> PResult<u32> {
+    take_while(1..=2, AsChar::is_dec_digit)
+        .try_map(u32::from_str)
+        .verify(|day| (1..=31).contains(day))
+        .parse_next(input)
+}
+
+pub(crate) fn month_number(input: &mut &str) -> PResult<Month> {
+    take_while(1..=2, AsChar::is_dec_digit)
+        .try_map(u8::from_str)
+        .try_map(Month::try_from)
+        .parse_next(input)
+}
+
+/// A four digit year, or a two digit one taken to be in the 2000s.
+pub(crate) fn year(input: &mut &str) -> PResult<i32> {
+    take_while(2..=4, AsChar::is_dec_digit)
+        .verify(|digits: &str| digits.len() != 3)
+        .try_map(|digits: &str| {
+            i32::from_str(digits).map(|year| if digits.len() == 2 { 2000 + year } else { year })
+        })
+        .parse_next(input)
+}
+
+/// Only a four digit year, where two digits could be mistaken for an hour.
+pub(crate) fn full_year(input: &mut &str) -> PResult<i32> {
+    take_while(4, AsChar::is_dec_digit)
+        .try_map(i32::from_str)
+        .parse_next(input)
+}
+
+pub(crate) fn date_separator(input: &mut &str) -> PResult<char> {
+    one_of(['/', '-']).parse_next(input)
+}
+
+pub(crate) fn hour(input: &mut &str) -> PResult<u32> {
+    take_while(1..=2, AsChar::is_dec_digit)
+        .try_map(u32::from_str)
+        .verify(|hour| *hour < 24)
+        .parse_next(input)
+}
+
+pub(crate) fn minute(input: &mut &str) -> PResult<u32> {
+    take_while(2, AsChar::is_dec_digit)
+        .try_map(u32::from_str)
+        .verify(|minute| *minute < 60)
+        .parse_next(input)
+}
+
+/// An hour on its own, like the "15" in "às 15".
+pub(crate) fn whole_hour(input: &mut &str) -> PResult<NaiveTime> {
+    hour.verify_map(|hour| NaiveTime::from_hms_opt(hour, 0, 0))
+        .parse_next(input)
+}
+
+/// A 24-hour clock reading: "15:30".
+pub(crate) fn clock_time(input: &mut &str) -> PResult<NaiveTime> {
+    (hour, ':', minute)
+        .verify_map(|(hour, _, minute)| NaiveTime::from_hms_opt(hour, minute, 0))
+        .parse_next(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Month, NaiveTime};
+    use winnow::Parser;
+
+    use super::{clock_time, day_of_month, hour, minute, month_number, year};
+
+    #[test]
+    fn test_day_of_month() {
+        assert_eq!(day_of_month.parse_peek("1"), Ok(("", 1)));
+        assert_eq!(day_of_month.parse_peek("07"), Ok(("", 7)));
+        assert_eq!(day_of_month.parse_peek("31"), Ok(("", 31)));
+        assert!(day_of_month.parse_peek("0").is_err());
+        assert!(day_of_month.parse_peek("32").is_err());
+    }
+
+    #[test]
+    fn test_month_number() {
+        assert_eq!(month_number.parse_peek("1"), Ok(("", Month::January)));
+        assert_eq!(month_number.parse_peek("08"), Ok(("", Month::August)));
+        assert_eq!(month_number.parse_peek("12"), Ok(("", Month::December)));
+        assert!(month_number.parse_peek("0").is_err());
+        assert!(month_number.parse_peek("13").is_err());
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(year.parse_peek("2025"), Ok(("", 2025)));
+        assert_eq!(year.parse_peek("24"), Ok(("", 2024)));
+        assert!(year.parse_peek("202").is_err());
+    }
+
+    #[test]
+    fn test_hour() {
+        assert_eq!(hour.parse_peek("0"), Ok(("", 0)));
+        assert_eq!(hour.parse_peek("09"), Ok(("", 9)));
+        assert_eq!(hour.parse_peek("23"), Ok(("", 23)));
+        assert!(hour.parse_peek("24").is_err());
+    }
+
+    #[test]
+    fn test_minute() {
+        assert_eq!(minute.parse_peek("00"), Ok(("", 0)));
+        assert_eq!(minute.parse_peek("59"), Ok(("", 59)));
+        assert!(minute.parse_peek("5").is_err());
+        assert!(minute.parse_peek("60").is_err());
+    }
+
+    #[test]
+    fn test_clock_time() {
+        assert_eq!(
+            clock_time.parse_peek("15:30"),
+            Ok(("", NaiveTime::from_hms_opt(15, 30, 0).unwrap()))
+        );
+        assert_eq!(
+            clock_time.parse_peek("9:05"),
+            Ok(("", NaiveTime::from_hms_opt(9, 5, 0).unwrap()))
+        );
+        assert!(clock_time.parse_peek("25:00").is_err());
+    }
+}
@@ -1,15 +1,182 @@
 use std::str::FromStr;
 
-use chrono::{Month, Weekday};
+use chrono::{Month, NaiveTime, Weekday};
 use winnow::{
     ascii::{digit1, space1},
-    combinator::{alt, opt},
-    error::ContextError,
-    PResult, Parser,
+    combinator::{alt, eof, opt, peek, preceded, repeat, terminated},
+    token::one_of,
+    Parser,
 };
 
-use crate::{HumanDateExpr, HumanDateKeyword, Ordinal};
+use super::range::{range, Connectives};
+use super::{parse_folded, PResult};
+use crate::error::GrammarError;
 
+use super::iberian::{next, numeric_date};
+use super::numeric::{clock_time, day_of_month, hour, minute, whole_hour, year, NOON};
+use crate::{
+    DayPeriod, Direction, Expected, Holiday, HumanDateExpr, HumanDateKeyword, HumanTime, Ordinal,
+    Period, TimeUnit, Year,
+};
+
+/// Words of this grammar, to suggest corrections for misspelled input.
+pub(crate) const VOCABULARY: &[&str] = &[
+    "hoje",
+    "amanhã",
+    "depois",
+    "de",
+    "ontem",
+    "anteontem",
+    "antes",
+    "daqui",
+    "em",
+    "há",
+    "faz",
+    "atrás",
+    "dia",
+    "dias",
+    "semana",
+    "semanas",
+    "mês",
+    "meses",
+    "ano",
+    "anos",
+    "esta",
+    "essa",
+    "esse",
+    "este",
+    "próxima",
+    "próximo",
+    "próx",
+    "primeira",
+    "primeiro",
+    "segunda",
+    "segundo",
+    "terceira",
+    "terceiro",
+    "quarta",
+    "quarto",
+    "quinta",
+    "quinto",
+    "terça",
+    "sexta",
+    "sábado",
+    "domingo",
+    "feira",
+    "seg",
+    "ter",
+    "qua",
+    "qui",
+    "sex",
+    "sáb",
+    "dom",
+    "janeiro",
+    "fevereiro",
+    "março",
+    "abril",
+    "maio",
+    "junho",
+    "julho",
+    "agosto",
+    "setembro",
+    "outubro",
+    "novembro",
+    "dezembro",
+    "jan",
+    "fev",
+    "mar",
+    "abr",
+    "mai",
+    "jun",
+    "jul",
+    "ago",
+    "set",
+    "out",
+    "nov",
+    "dez",
+    "às",
+    "à",
+    "as",
+    "a",
+    "da",
+    "pela",
+    "na",
+    "manhã",
+    "tarde",
+    "noite",
+    "meio",
+    "meia",
+    "um",
+    "uma",
+    "dois",
+    "duas",
+    "três",
+    "quatro",
+    "cinco",
+    "seis",
+    "sete",
+    "oito",
+    "nove",
+    "onze",
+    "doze",
+    "treze",
+    "quatorze",
+    "catorze",
+    "quinze",
+    "dezesseis",
+    "dezessete",
+    "dezoito",
+    "dezenove",
+    "vinte",
+    "do",
+    "deste",
+    "desse",
+    "que",
+    "vem",
+    "seguinte",
+    "passado",
+    "último",
+    "última",
+    "penúltimo",
+    "penúltima",
+    "o",
+    "desde",
+    "até",
+    "ao",
+    "entre",
+    "e",
+    "fim",
+    "passada",
+    "úteis",
+    "útil",
+    "natal",
+    "novo",
+    "réveillon",
+    "páscoa",
+    "carnaval",
+    "santa",
+    "paixão",
+    "corpus",
+    "christi",
+    "tiradentes",
+    "trabalho",
+    "trabalhador",
+    "independência",
+    "finados",
+    "proclamação",
+    "república",
+    "consciência",
+    "negra",
+    "das",
+    "dos",
+    "mães",
+    "pais",
+    "black",
+    "friday",
+    "após",
+];
+
+#[derive(Default)]
 pub struct HumanDateParserBrazillianPortugueseParser;
 
 impl HumanDateParserBrazillianPortugueseParser {
@@ -18,43 +185,294 @@ impl HumanDateParserBrazillianPortugueseParser {
     }
 }
 
-impl Parser<&str, HumanDateExpr, ContextError> for HumanDateParserBrazillianPortugueseParser {
+impl Parser<&str, HumanDateExpr, GrammarError> for HumanDateParserBrazillianPortugueseParser {
     fn parse_next(&mut self, input: &mut &str) -> PResult<HumanDateExpr> {
-        let mut parser = alt((
+        parse_folded(input, grammar)
+    }
+}
+
+/// The grammar, which only matches folded text.
+pub(crate) fn grammar(input: &mut &str) -> PResult<HumanDateExpr> {
+    portuguese(BRAZILIAN).parse_next(input)
+}
+
+type Combinator<O> = fn(&mut &str) -> PResult<O>;
+
+/// The combinators where the Portuguese dialects differ. Everything else is
+/// shared, so [`super::pt_pt`] only brings its own of these.
+#[derive(Clone, Copy)]
+pub(crate) struct Dialect {
+    pub(crate) number: Combinator<u64>,
+    pub(crate) in_n: Combinator<(u64, TimeUnit)>,
+    pub(crate) relative_period: Combinator<(Period, i32)>,
+    pub(crate) weekday_of_next_week: Combinator<Weekday>,
+}
+
+const BRAZILIAN: Dialect = Dialect {
+    number,
+    in_n,
+    relative_period,
+    weekday_of_next_week,
+};
+
+/// The grammar of a Portuguese dialect.
+pub(crate) fn portuguese<'a>(
+    dialect: Dialect,
+) -> impl Parser<&'a str, HumanDateExpr, GrammarError> {
+    let date = date(dialect);
+    alt((
+        range(date, day_of_month, &CONNECTIVES),
+        (date, opt(preceded(space1, time))).map(|(date, time)| date.with_time(time)),
+        time.map(HumanDateExpr::Time),
+    ))
+}
+
+fn date<'a>(dialect: Dialect) -> impl Parser<&'a str, HumanDateExpr, GrammarError> + Copy {
+    move |input: &mut &'a str| {
+        alt((offset(dialect), next_to_holiday, simple_date(dialect))).parse_next(input)
+    }
+}
+
+/// A date that is not an offset from another one.
+fn simple_date<'a>(dialect: Dialect) -> impl Parser<&'a str, HumanDateExpr, GrammarError> + Copy {
+    move |input: &mut &'a str| {
+        alt((
             keyword.map(HumanDateExpr::Keyword),
-            in_n_days.map(HumanDateExpr::InNDays),
-            ordinal_weekday_of_month.map(|(ordinal, weekday, month)| {
-                HumanDateExpr::OrdinalWeekdayOfMonth(ordinal, weekday, month)
+            business_days(dialect.number).map(HumanDateExpr::BusinessDays),
+            business_day_of_relative_month
+                .map(|(ordinal, n)| HumanDateExpr::BusinessDayOfRelativeMonth(ordinal, n)),
+            dialect.in_n.map(|(n, unit)| HumanDateExpr::InN(n, unit)),
+            n_ago(dialect.number).map(|(n, unit)| HumanDateExpr::NAgo(n, unit)),
+            absolute.map(|(day, month, year)| HumanDateExpr::Absolute { day, month, year }),
+            ordinal_weekday_of_month.map(|(ordinal, weekday, month, year)| {
+                HumanDateExpr::OrdinalWeekdayOfMonth(ordinal, weekday, month, year)
             }),
-            this_week_weekday.map(HumanDateExpr::ThisWeekWeekday),
+            ordinal_weekday_of_relative_month.map(|(ordinal, weekday, n)| {
+                HumanDateExpr::OrdinalWeekdayOfRelativeMonth(ordinal, weekday, n)
+            }),
+            (dialect.relative_period).map(|(period, n)| HumanDateExpr::RelativePeriod(period, n)),
+            holiday.map(|(holiday, year)| HumanDateExpr::Holiday(holiday, year)),
+            (dialect.weekday_of_next_week).map(HumanDateExpr::WeekdayOfNextWeek),
             next_week_weekday.map(HumanDateExpr::NextWeekWeekday),
-        ));
-        parser.parse_next(input)
+            this_week_weekday.map(HumanDateExpr::ThisWeekWeekday),
+        ))
+        .parse_next(input)
     }
 }
 
-fn keyword(input: &mut &str) -> PResult<HumanDateKeyword> {
+/// "de segunda a sexta", "entre 10 e 15 de maio", "desde ontem até hoje",
+/// "segunda até sexta"...
+pub(crate) const CONNECTIVES: Connectives = Connectives {
+    from: &["desde", "de", "do"],
+    to: &["ate", "ao", "à", "a"],
+    between: "entre",
+    and: "e",
+    until: &["ate"],
+};
+
+pub(crate) fn keyword(input: &mut &str) -> PResult<HumanDateKeyword> {
     alt((
         "hoje".value(HumanDateKeyword::Today),
-        "amanhã".value(HumanDateKeyword::Tomorrow),
-        "depois de amanhã".value(HumanDateKeyword::AfterTomorrow),
+        "amanha".value(HumanDateKeyword::Tomorrow),
+        "depois de amanha".value(HumanDateKeyword::AfterTomorrow),
+        "ontem".value(HumanDateKeyword::Yesterday),
+        alt(("anteontem", "antes de ontem")).value(HumanDateKeyword::BeforeYesterday),
+    ))
+    .context(Expected::Keyword.context())
+    .parse_next(input)
+}
+
+/// "em 5 dias úteis", "há 2 dias úteis", "próximo dia útil"...
+fn business_days<'a>(number: Combinator<u64>) -> impl Parser<&'a str, i64, GrammarError> {
+    let quantity = move |input: &mut &'a str| {
+        terminated(
+            number,
+            (
+                space1,
+                alt(("dias uteis", "dia util")).context(Expected::Unit.context()),
+            ),
+        )
+        .try_map(i64::try_from)
+        .parse_next(input)
+    };
+    alt((
+        preceded((alt(("daqui a", "daqui", "em")), space1), quantity),
+        preceded((alt(("ha", "faz")), space1), quantity).map(|n| -n),
+        terminated(quantity, (space1, "atras")).map(|n| -n),
+        (next, space1, "dia util").value(1),
+    ))
+}
+
+/// "primeiro dia útil do mês", "último dia útil do mês que vem"...
+pub(crate) fn business_day_of_relative_month(input: &mut &str) -> PResult<(Ordinal, i32)> {
+    let (_, ordinal, _, _, _, n) = (
+        opt(("o", space1)),
+        ordinal,
+        space1,
+        "dia util",
+        space1,
+        which_month,
+    )
+        .parse_next(input)?;
+    Ok((ordinal, n))
+}
+
+fn in_n(input: &mut &str) -> PResult<(u64, TimeUnit)> {
+    preceded((alt(("daqui a", "daqui", "em")), space1), quantity(number)).parse_next(input)
+}
+
+fn n_ago<'a>(number: Combinator<u64>) -> impl Parser<&'a str, (u64, TimeUnit), GrammarError> {
+    alt((
+        preceded((alt(("ha", "faz")), space1), quantity(number)),
+        terminated(quantity(number), (space1, "atras")),
+    ))
+}
+
+/// "3 dias", "duas semanas"...
+pub(crate) fn quantity<'a>(
+    number: Combinator<u64>,
+) -> impl Parser<&'a str, (u64, TimeUnit), GrammarError> + Copy {
+    move |input: &mut &'a str| {
+        let (n, _, unit) = (number, space1, unit).parse_next(input)?;
+        Ok((n, unit))
+    }
+}
+
+pub(crate) fn unit(input: &mut &str) -> PResult<TimeUnit> {
+    alt((
+        alt(("dias", "dia")).value(TimeUnit::Day),
+        alt(("semanas", "semana")).value(TimeUnit::Week),
+        alt(("meses", "mes")).value(TimeUnit::Month),
+        alt(("anos", "ano")).value(TimeUnit::Year),
+    ))
+    .context(Expected::Unit.context())
+    .parse_next(input)
+}
+
+/// "esta semana", "mês que vem", "próximo fim de semana", "ano passado"...
+pub(crate) fn relative_period(input: &mut &str) -> PResult<(Period, i32)> {
+    alt((
+        preceded((this, space1), period).map(|period| (period, 0)),
+        preceded((next, space1), period).map(|period| (period, 1)),
+        (
+            period,
+            space1,
+            alt((
+                alt(("que vem", "seguinte")).value(1),
+                alt(("passado", "passada")).value(-1),
+            )),
+        )
+            .map(|(period, _, n)| (period, n)),
+        "fim de semana".value((Period::Weekend, 0)),
     ))
     .parse_next(input)
 }
 
-fn in_n_days(input: &mut &str) -> PResult<u64> {
-    let (_, n, _) = (
-        (alt(("daqui", "em")), space1),
-        number,
-        (space1, "dia", opt('s')),
+fn period(input: &mut &str) -> PResult<Period> {
+    alt((
+        "fim de semana".value(Period::Weekend),
+        "semana".value(Period::Week),
+        "mes".value(Period::Month),
+        "ano".value(Period::Year),
+    ))
+    .parse_next(input)
+}
+
+/// "Natal", "o Carnaval de 2026", "Dia das Mães do ano que vem"...
+pub(crate) fn holiday(input: &mut &str) -> PResult<(Holiday, Option<Year>)> {
+    let (_, holiday, year) = (
+        opt((alt(("o", "a", "no", "na", "do", "da")), space1)),
+        holiday_name,
+        opt(preceded(space1, which_year)),
     )
         .parse_next(input)?;
-    Ok(n)
+    Ok((holiday, year))
 }
 
-fn this_week_weekday(input: &mut &str) -> PResult<Weekday> {
-    let (_, weekday) = (opt((this, space1)), weekday).parse_next(input)?;
-    Ok(weekday)
+fn holiday_name(input: &mut &str) -> PResult<Holiday> {
+    alt((
+        "natal".value(Holiday::Christmas),
+        alt(("ano novo", "reveillon")).value(Holiday::NewYear),
+        "pascoa".value(Holiday::Easter),
+        "carnaval".value(Holiday::Carnival),
+        alt((
+            "sexta-feira santa",
+            "sexta feira santa",
+            "sexta-feira da paixao",
+            "sexta feira da paixao",
+        ))
+        .value(Holiday::GoodFriday),
+        "corpus christi".value(Holiday::CorpusChristi),
+        "tiradentes".value(Holiday::Tiradentes),
+        alt(("dia do trabalhador", "dia do trabalho")).value(Holiday::LabourDay),
+        alt(("dia da independencia", "independencia")).value(Holiday::Independence),
+        alt(("dia de finados", "finados")).value(Holiday::AllSouls),
+        "proclamacao da republica".value(Holiday::Republic),
+        alt(("dia da consciencia negra", "consciencia negra")).value(Holiday::BlackConsciousness),
+        "dia das maes".value(Holiday::MothersDay),
+        "dia dos pais".value(Holiday::FathersDay),
+        "black friday".value(Holiday::BlackFriday),
+    ))
+    .parse_next(input)
+}
+
+/// How many offsets can be chained, so that input repeating "1 dia antes de"
+/// can't nest expressions without limit.
+const MAX_OFFSETS: usize = 8;
+
+/// "2 dias antes do Natal", "3 dias depois de amanhã", "30 dias após o dia
+/// 10 de maio"... Offsets can be chained, as in "uma semana depois de 2 dias
+/// antes do Natal", up to [`MAX_OFFSETS`] of them.
+fn offset<'a>(dialect: Dialect) -> impl Parser<&'a str, HumanDateExpr, GrammarError> {
+    move |input: &mut &'a str| {
+        let offset_delta = (quantity(dialect.number), space1, direction);
+        let (deltas, base): (Vec<_>, _) = (
+            repeat(1..=MAX_OFFSETS, terminated(offset_delta, space1)),
+            simple_date(dialect),
+        )
+            .parse_next(input)?;
+        Ok(deltas
+            .into_iter()
+            .rev()
+            .fold(base, |base, (delta, _, direction)| HumanDateExpr::Offset {
+                base: Box::new(base),
+                delta,
+                direction,
+            }))
+    }
+}
+
+/// "antes do", "depois de", "após o"...
+fn direction(input: &mut &str) -> PResult<Direction> {
+    alt((
+        terminated("antes", (space1, alt(("do", "da", "de")))).value(Direction::Before),
+        terminated("depois", (space1, alt(("do", "da", "de")))).value(Direction::After),
+        terminated("apos", opt((space1, alt(("o", "a")), peek(space1)))).value(Direction::After),
+    ))
+    .parse_next(input)
+}
+
+/// "depois do Carnaval", "antes do Natal": the day after or before a holiday,
+/// as "depois de amanhã" is the day after tomorrow.
+fn next_to_holiday(input: &mut &str) -> PResult<HumanDateExpr> {
+    let (direction, _, (holiday, year)) = (direction, space1, holiday).parse_next(input)?;
+    Ok(HumanDateExpr::Offset {
+        base: Box::new(HumanDateExpr::Holiday(holiday, year)),
+        delta: (1, TimeUnit::Day),
+        direction,
+    })
+}
+
+pub(crate) fn this_week_weekday(input: &mut &str) -> PResult<Weekday> {
+    alt((
+        preceded((this, space1), weekday),
+        // On their own, these are more often words than weekdays: "vou ter que ir".
+        weekday
+            .with_taken()
+            .verify(|(_, taken)| !["ter", "qua", "sex", "dom"].contains(taken))
+            .map(|(weekday, _)| weekday),
+    ))
+    .parse_next(input)
 }
 
 fn next_week_weekday(input: &mut &str) -> PResult<Weekday> {
@@ -62,26 +480,171 @@ fn next_week_weekday(input: &mut &str) -> PResult<Weekday> {
     Ok(weekday)
 }
 
-fn ordinal_weekday_of_month(input: &mut &str) -> PResult<(Ordinal, Weekday, Month)> {
-    let (ordinal, _, weekday, _, _, _, month) =
-        (ordinal, space1, weekday, space1, "de", space1, month).parse_next(input)?;
-    Ok((ordinal, weekday, month))
+/// "segunda da próxima semana", "sexta na semana que vem"...
+pub(crate) fn weekday_of_next_week(input: &mut &str) -> PResult<Weekday> {
+    let (weekday, _, _, _, _) = (
+        weekday,
+        space1,
+        alt(("da", "na")),
+        space1,
+        alt(((next, space1, "semana").void(), "semana que vem".void())),
+    )
+        .parse_next(input)?;
+    Ok(weekday)
 }
 
-fn this(input: &mut &str) -> PResult<()> {
-    alt(("esta", "essa", "esse", "este"))
-        .void()
-        .parse_next(input)
+pub(crate) fn ordinal_weekday_of_month(
+    input: &mut &str,
+) -> PResult<(Ordinal, Weekday, Month, Option<Year>)> {
+    let (_, ordinal, _, weekday, _, _, _, month, year) = (
+        opt((alt(("a", "o")), space1)),
+        ordinal,
+        space1,
+        weekday,
+        space1,
+        "de",
+        space1,
+        month,
+        opt(preceded(space1, which_year)),
+    )
+        .parse_next(input)?;
+    Ok((ordinal, weekday, month, year))
+}
+
+/// "primeira segunda do mês", "última sexta do mês que vem"...
+pub(crate) fn ordinal_weekday_of_relative_month(
+    input: &mut &str,
+) -> PResult<(Ordinal, Weekday, i32)> {
+    let (_, ordinal, _, weekday, _, n) = (
+        opt((alt(("a", "o")), space1)),
+        ordinal,
+        space1,
+        weekday,
+        space1,
+        which_month,
+    )
+        .parse_next(input)?;
+    Ok((ordinal, weekday, n))
+}
+
+/// "do mês", "do próximo mês", "do mês passado"...
+fn which_month(input: &mut &str) -> PResult<i32> {
+    alt((
+        alt((
+            "do mes que vem".void(),
+            "do mes seguinte".void(),
+            ("do", space1, next, space1, "mes").void(),
+        ))
+        .value(1),
+        "do mes passado".value(-1),
+        alt(("do mes", "deste mes", "desse mes")).value(0),
+    ))
+    .parse_next(input)
+}
+
+/// "de 2026", "do ano que vem", "deste ano"...
+fn which_year(input: &mut &str) -> PResult<Year> {
+    alt((
+        preceded(("de", space1), year).map(Year::Absolute),
+        alt((
+            "do ano que vem".void(),
+            "do ano seguinte".void(),
+            ("do", space1, next, space1, "ano").void(),
+        ))
+        .value(Year::Relative(1)),
+        alt(("deste ano", "desse ano")).value(Year::Relative(0)),
+        "do ano passado".value(Year::Relative(-1)),
+    ))
+    .parse_next(input)
+}
+
+pub(crate) fn absolute(input: &mut &str) -> PResult<(u32, Option<Month>, Option<i32>)> {
+    alt((
+        numeric_date.map(|(day, month, year)| (day, Some(month), year)),
+        (
+            opt(("dia", space1)),
+            day_of_month,
+            opt('º'),
+            space1,
+            "de",
+            space1,
+            month,
+            opt(preceded((space1, "de", space1), year)),
+        )
+            .map(|(_, day, _, _, _, _, month, year)| (day, Some(month), year)),
+        preceded(("dia", space1), day_of_month).map(|day| (day, None, None)),
+    ))
+    .parse_next(input)
+}
+
+pub(crate) fn time(input: &mut &str) -> PResult<HumanTime> {
+    alt((
+        // Folding keeps the grave of "às", so a bare hour is only taken after
+        // it: in "as 3 lojas" or "de 1 a 3", the number is not an hour.
+        preceded(("às", space1), alt((clock, bare_hour))).map(HumanTime::At),
+        preceded((alt(("as", "à", "a")), space1), clock).map(HumanTime::At),
+        clock.map(HumanTime::At),
+        preceded(
+            (alt(("de", "da", "pela", "à", "a", "na")), space1),
+            day_period,
+        )
+        .map(HumanTime::Period),
+    ))
+    .context(Expected::Time.context())
+    .parse_next(input)
+}
+
+/// An hour without "h", as in "às 15" or "às 3 horas", but not "às 3 lojas",
+/// where a noun follows it.
+fn bare_hour(input: &mut &str) -> PResult<NaiveTime> {
+    terminated(
+        whole_hour,
+        alt((
+            (space1, alt(("horas", "hora"))).void(),
+            peek(alt((
+                eof.void(),
+                one_of(|c: char| c.is_ascii_punctuation()).void(),
+            ))),
+        )),
+    )
+    .parse_next(input)
+}
+
+fn clock(input: &mut &str) -> PResult<NaiveTime> {
+    alt((
+        alt(("meio-dia", "meio dia")).value(NOON),
+        alt(("meia-noite", "meia noite")).value(NaiveTime::MIN),
+        (
+            hour,
+            opt('h'),
+            space1,
+            alt(("da", "de")),
+            space1,
+            day_period,
+        )
+            .verify_map(|(hour, _, _, _, _, period)| period.at(hour, 0)),
+        clock_time,
+        (hour, 'h', opt(minute))
+            .verify_map(|(hour, _, minute)| NaiveTime::from_hms_opt(hour, minute.unwrap_or(0), 0)),
+    ))
+    .parse_next(input)
 }
 
-fn next(input: &mut &str) -> PResult<()> {
+fn day_period(input: &mut &str) -> PResult<DayPeriod> {
     alt((
-        "próxima", "proxima", "próximo", "proximo", "próx.", "prox.", "próx", "prox",
+        "manha".value(DayPeriod::Morning),
+        "tarde".value(DayPeriod::Afternoon),
+        "noite".value(DayPeriod::Evening),
     ))
-    .void()
     .parse_next(input)
 }
 
+fn this(input: &mut &str) -> PResult<()> {
+    alt(("esta", "essa", "esse", "este"))
+        .void()
+        .parse_next(input)
+}
+
 fn ordinal(input: &mut &str) -> PResult<Ordinal> {
     alt((
         alt(("primeira", "primeiro")).value(Ordinal::First),
@@ -89,11 +652,13 @@ fn ordinal(input: &mut &str) -> PResult<Ordinal> {
         alt(("terceira", "terceiro")).value(Ordinal::Third),
         alt(("quarta", "quarto")).value(Ordinal::Fourth),
         alt(("quinta", "quinto")).value(Ordinal::Fifth),
+        alt(("ultima", "ultimo")).value(Ordinal::Last),
+        alt(("penultima", "penultimo")).value(Ordinal::SecondToLast),
     ))
     .parse_next(input)
 }
 
-fn number(input: &mut &str) -> PResult<u64> {
+pub(crate) fn number(input: &mut &str) -> PResult<u64> {
     alt((
         digit1.try_map(FromStr::from_str),
         "dezessete".value(17),
@@ -105,7 +670,7 @@ fn number(input: &mut &str) -> PResult<u64> {
         "vinte".value(20),
         "treze".value(13),
         "quatro".value(4),
-        "três".value(3),
+        "tres".value(3),
         "onze".value(11),
         "doze".value(12),
         "cinco".value(5),
@@ -113,43 +678,35 @@ fn number(input: &mut &str) -> PResult<u64> {
         "seis".value(6),
         "oito".value(8),
         "nove".value(9),
-        "dois".value(2),
+        alt(("dois", "duas")).value(2),
         "dez".value(10),
-        "um".value(1),
+        alt(("uma", "um")).value(1),
     ))
+    .context(Expected::Number.context())
     .parse_next(input)
 }
 
-fn weekday(input: &mut &str) -> PResult<Weekday> {
+pub(crate) fn weekday(input: &mut &str) -> PResult<Weekday> {
     alt((
         alt(("segunda-feira", "segunda feira", "segunda", "seg.", "seg")).value(Weekday::Mon),
-        alt((
-            "terça-feira",
-            "terca-feira",
-            "terça feira",
-            "terca feira",
-            "terça",
-            "terca",
-            "ter.",
-            "ter",
-        ))
-        .value(Weekday::Tue),
+        alt(("terca-feira", "terca feira", "terca", "ter.", "ter")).value(Weekday::Tue),
         alt(("quarta-feira", "quarta feira", "quarta", "qua.", "qua")).value(Weekday::Wed),
         alt(("quinta-feira", "quinta feira", "quinta", "qui.", "qui")).value(Weekday::Thu),
         alt(("sexta-feira", "sexta feira", "sexta", "sex.", "sex")).value(Weekday::Fri),
-        alt(("sábado", "sabado", "sáb.", "sab.", "sáb", "sab")).value(Weekday::Sat),
+        alt(("sabado", "sab.", "sab")).value(Weekday::Sat),
         alt(("domingo", "dom.", "dom")).value(Weekday::Sun),
     ))
+    .context(Expected::Weekday.context())
     .parse_next(input)
 }
 
-fn month(input: &mut &str) -> PResult<Month> {
+pub(crate) fn month(input: &mut &str) -> PResult<Month> {
     alt((
         alt(("janeiro", "jan.", "jan")).value(Month::January),
         alt(("fevereiro", "fev.", "fev")).value(Month::February),
-        alt(("março", "marco", "mar.", "mar")).value(Month::March),
+        alt(("marco", "mar.", "mar")).value(Month::March),
         alt(("abril", "abr.", "abr")).value(Month::April),
-        alt(("maio", "mai.", "maio")).value(Month::May),
+        alt(("maio", "mai.")).value(Month::May),
         alt(("junho", "jun.", "jun")).value(Month::June),
         alt(("julho", "jul.", "jul")).value(Month::July),
         alt(("agosto", "ago.", "ago")).value(Month::August),
@@ -158,16 +715,22 @@ fn month(input: &mut &str) -> PResult<Month> {
         alt(("novembro", "nov.", "nov")).value(Month::November),
         alt(("dezembro", "dez.", "dez")).value(Month::December),
     ))
+    .context(Expected::Month.context())
     .parse_next(input)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{HumanDateExpr, HumanDateKeyword, Ordinal};
-    use chrono::{Month, Weekday};
+    use crate::{
+        locales::Locale, parse, DayPeriod, Direction, Holiday, HumanDateExpr, HumanDateKeyword,
+        HumanTime, Ordinal, TimeUnit, Year,
+    };
+    use chrono::{Month, NaiveTime, Weekday};
     use winnow::Parser;
 
-    use super::{next, number, this, weekday, HumanDateParserBrazillianPortugueseParser};
+    use super::{
+        next, number, ordinal, this, weekday, HumanDateParserBrazillianPortugueseParser, Period,
+    };
 
     #[test]
     fn text_keywords() {
@@ -184,6 +747,45 @@ mod tests {
             parser.parse_peek("depois de amanhã"),
             Ok(("", HumanDateExpr::Keyword(HumanDateKeyword::AfterTomorrow)))
         );
+        assert_eq!(
+            parser.parse_peek("ontem"),
+            Ok(("", HumanDateExpr::Keyword(HumanDateKeyword::Yesterday)))
+        );
+        assert_eq!(
+            parser.parse_peek("anteontem"),
+            Ok((
+                "",
+                HumanDateExpr::Keyword(HumanDateKeyword::BeforeYesterday)
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("antes de ontem"),
+            Ok((
+                "",
+                HumanDateExpr::Keyword(HumanDateKeyword::BeforeYesterday)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_n_days_ago() {
+        let mut parser = HumanDateParserBrazillianPortugueseParser::new();
+        assert_eq!(
+            parser.parse_peek("há 3 dias"),
+            Ok(("", HumanDateExpr::NAgo(3, TimeUnit::Day)))
+        );
+        assert_eq!(
+            parser.parse_peek("faz um dia"),
+            Ok(("", HumanDateExpr::NAgo(1, TimeUnit::Day)))
+        );
+        assert_eq!(
+            parser.parse_peek("3 dias atrás"),
+            Ok(("", HumanDateExpr::NAgo(3, TimeUnit::Day)))
+        );
+        assert_eq!(
+            parser.parse_peek("dois dias atras"),
+            Ok(("", HumanDateExpr::NAgo(2, TimeUnit::Day)))
+        );
     }
 
     #[test]
@@ -191,19 +793,60 @@ mod tests {
         let mut parser = HumanDateParserBrazillianPortugueseParser::new();
         assert_eq!(
             parser.parse_peek("daqui 2 dias"),
-            Ok(("", HumanDateExpr::InNDays(2)))
+            Ok(("", HumanDateExpr::InN(2, TimeUnit::Day)))
         );
         assert_eq!(
             parser.parse_peek("em 2 dias"),
-            Ok(("", HumanDateExpr::InNDays(2)))
+            Ok(("", HumanDateExpr::InN(2, TimeUnit::Day)))
         );
         assert_eq!(
             parser.parse_peek("daqui dois dias"),
-            Ok(("", HumanDateExpr::InNDays(2)))
+            Ok(("", HumanDateExpr::InN(2, TimeUnit::Day)))
         );
         assert_eq!(
             parser.parse_peek("em dois dias"),
-            Ok(("", HumanDateExpr::InNDays(2)))
+            Ok(("", HumanDateExpr::InN(2, TimeUnit::Day)))
+        );
+    }
+
+    #[test]
+    fn test_in_n_units() {
+        let mut parser = HumanDateParserBrazillianPortugueseParser::new();
+        assert_eq!(
+            parser.parse_peek("em 2 semanas"),
+            Ok(("", HumanDateExpr::InN(2, TimeUnit::Week)))
+        );
+        assert_eq!(
+            parser.parse_peek("em uma semana"),
+            Ok(("", HumanDateExpr::InN(1, TimeUnit::Week)))
+        );
+        assert_eq!(
+            parser.parse_peek("daqui 3 meses"),
+            Ok(("", HumanDateExpr::InN(3, TimeUnit::Month)))
+        );
+        assert_eq!(
+            parser.parse_peek("daqui a 3 dias"),
+            Ok(("", HumanDateExpr::InN(3, TimeUnit::Day)))
+        );
+        assert_eq!(
+            parser.parse_peek("em um mês"),
+            Ok(("", HumanDateExpr::InN(1, TimeUnit::Month)))
+        );
+        assert_eq!(
+            parser.parse_peek("em um ano"),
+            Ok(("", HumanDateExpr::InN(1, TimeUnit::Year)))
+        );
+        assert_eq!(
+            parser.parse_peek("há duas semanas"),
+            Ok(("", HumanDateExpr::NAgo(2, TimeUnit::Week)))
+        );
+        assert_eq!(
+            parser.parse_peek("6 meses atrás"),
+            Ok(("", HumanDateExpr::NAgo(6, TimeUnit::Month)))
+        );
+        assert_eq!(
+            parser.parse_peek("faz dez anos"),
+            Ok(("", HumanDateExpr::NAgo(10, TimeUnit::Year)))
         );
     }
 
@@ -243,6 +886,14 @@ mod tests {
     #[test]
     fn test_next_week_weekday() {
         let mut parser = HumanDateParserBrazillianPortugueseParser::new();
+        assert_eq!(
+            parser.parse_peek("sexta da semana que vem"),
+            Ok(("", HumanDateExpr::WeekdayOfNextWeek(Weekday::Fri)))
+        );
+        assert_eq!(
+            parser.parse_peek("segunda-feira na próxima semana"),
+            Ok(("", HumanDateExpr::WeekdayOfNextWeek(Weekday::Mon)))
+        );
         assert_eq!(
             parser.parse_peek("próxima segunda"),
             Ok(("", HumanDateExpr::NextWeekWeekday(Weekday::Mon)))
@@ -283,7 +934,8 @@ mod tests {
                 HumanDateExpr::OrdinalWeekdayOfMonth(
                     Ordinal::First,
                     Weekday::Sun,
-                    Month::September
+                    Month::September,
+                    None
                 )
             ))
         );
@@ -294,7 +946,8 @@ mod tests {
                 HumanDateExpr::OrdinalWeekdayOfMonth(
                     Ordinal::First,
                     Weekday::Thu,
-                    Month::September
+                    Month::September,
+                    None
                 )
             ))
         );
@@ -305,72 +958,762 @@ mod tests {
                 HumanDateExpr::OrdinalWeekdayOfMonth(
                     Ordinal::Second,
                     Weekday::Sun,
-                    Month::September
+                    Month::September,
+                    None
                 )
             ))
         );
     }
 
     #[test]
-    fn test_weekday() {
-        assert_eq!(weekday.parse_peek("segunda-feira"), Ok(("", Weekday::Mon)));
-        assert_eq!(weekday.parse_peek("segunda feira"), Ok(("", Weekday::Mon)));
-        assert_eq!(weekday.parse_peek("seg."), Ok(("", Weekday::Mon)));
-        assert_eq!(weekday.parse_peek("seg"), Ok(("", Weekday::Mon)));
-        assert_eq!(weekday.parse_peek("terça-feira"), Ok(("", Weekday::Tue)));
-        assert_eq!(weekday.parse_peek("terca-feira"), Ok(("", Weekday::Tue)));
-        assert_eq!(weekday.parse_peek("terça feira"), Ok(("", Weekday::Tue)));
-        assert_eq!(weekday.parse_peek("terca feira"), Ok(("", Weekday::Tue)));
-        assert_eq!(weekday.parse_peek("ter."), Ok(("", Weekday::Tue)));
-        assert_eq!(weekday.parse_peek("ter"), Ok(("", Weekday::Tue)));
-        assert_eq!(weekday.parse_peek("quarta-feira"), Ok(("", Weekday::Wed)));
-        assert_eq!(weekday.parse_peek("quarta feira"), Ok(("", Weekday::Wed)));
-        assert_eq!(weekday.parse_peek("qua."), Ok(("", Weekday::Wed)));
-        assert_eq!(weekday.parse_peek("qua"), Ok(("", Weekday::Wed)));
-        assert_eq!(weekday.parse_peek("quinta-feira"), Ok(("", Weekday::Thu)));
-        assert_eq!(weekday.parse_peek("quinta feira"), Ok(("", Weekday::Thu)));
-        assert_eq!(weekday.parse_peek("qui."), Ok(("", Weekday::Thu)));
-        assert_eq!(weekday.parse_peek("qui"), Ok(("", Weekday::Thu)));
-        assert_eq!(weekday.parse_peek("sexta-feira"), Ok(("", Weekday::Fri)));
-        assert_eq!(weekday.parse_peek("sexta feira"), Ok(("", Weekday::Fri)));
-        assert_eq!(weekday.parse_peek("sex."), Ok(("", Weekday::Fri)));
-        assert_eq!(weekday.parse_peek("sex"), Ok(("", Weekday::Fri)));
-        assert_eq!(weekday.parse_peek("sábado"), Ok(("", Weekday::Sat)));
-        assert_eq!(weekday.parse_peek("sabado"), Ok(("", Weekday::Sat)));
-        assert_eq!(weekday.parse_peek("sáb."), Ok(("", Weekday::Sat)));
-        assert_eq!(weekday.parse_peek("sab."), Ok(("", Weekday::Sat)));
-        assert_eq!(weekday.parse_peek("sáb"), Ok(("", Weekday::Sat)));
-        assert_eq!(weekday.parse_peek("domingo"), Ok(("", Weekday::Sun)));
-        assert_eq!(weekday.parse_peek("dom."), Ok(("", Weekday::Sun)));
-        assert_eq!(weekday.parse_peek("dom"), Ok(("", Weekday::Sun)));
-    }
-
-    #[test]
-    fn test_this() {
-        assert_eq!(this.parse_peek("esta"), Ok(("", ())));
-        assert_eq!(this.parse_peek("essa"), Ok(("", ())));
-        assert_eq!(this.parse_peek("esse"), Ok(("", ())));
-        assert_eq!(this.parse_peek("este"), Ok(("", ())));
-    }
-
-    #[test]
-    fn test_next() {
-        assert_eq!(next.parse_peek("próxima"), Ok(("", ())));
-        assert_eq!(next.parse_peek("proxima"), Ok(("", ())));
-        assert_eq!(next.parse_peek("próximo"), Ok(("", ())));
-        assert_eq!(next.parse_peek("proximo"), Ok(("", ())));
-        assert_eq!(next.parse_peek("próx."), Ok(("", ())));
-        assert_eq!(next.parse_peek("prox."), Ok(("", ())));
-        assert_eq!(next.parse_peek("prox"), Ok(("", ())));
+    fn test_last_weekday_of_month() {
+        let mut parser = HumanDateParserBrazillianPortugueseParser::new();
+        assert_eq!(
+            parser.parse_peek("ultimo domingo de outubro"),
+            Ok((
+                "",
+                HumanDateExpr::OrdinalWeekdayOfMonth(
+                    Ordinal::Last,
+                    Weekday::Sun,
+                    Month::October,
+                    None
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("a ultima sexta de novembro"),
+            Ok((
+                "",
+                HumanDateExpr::OrdinalWeekdayOfMonth(
+                    Ordinal::Last,
+                    Weekday::Fri,
+                    Month::November,
+                    None
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("o penultimo sabado de dezembro de 2025"),
+            Ok((
+                "",
+                HumanDateExpr::OrdinalWeekdayOfMonth(
+                    Ordinal::SecondToLast,
+                    Weekday::Sat,
+                    Month::December,
+                    Some(Year::Absolute(2025))
+                )
+            ))
+        );
     }
 
     #[test]
-    fn test_number() {
-        assert_eq!(number(&mut "1"), Ok(1));
-        assert_eq!(number(&mut "01"), Ok(1));
+    fn test_ordinal_weekday_of_relative_month() {
+        let mut parser = HumanDateParserBrazillianPortugueseParser::new();
+        assert_eq!(
+            parser.parse_peek("primeira segunda do mes"),
+            Ok((
+                "",
+                HumanDateExpr::OrdinalWeekdayOfRelativeMonth(Ordinal::First, Weekday::Mon, 0)
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("segunda sexta do proximo mes"),
+            Ok((
+                "",
+                HumanDateExpr::OrdinalWeekdayOfRelativeMonth(Ordinal::Second, Weekday::Fri, 1)
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("ultima quinta do mes que vem"),
+            Ok((
+                "",
+                HumanDateExpr::OrdinalWeekdayOfRelativeMonth(Ordinal::Last, Weekday::Thu, 1)
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("a terceira quarta do mes passado as 10h"),
+            Ok((
+                "",
+                HumanDateExpr::DateTime(
+                    Box::new(HumanDateExpr::OrdinalWeekdayOfRelativeMonth(
+                        Ordinal::Third,
+                        Weekday::Wed,
+                        -1
+                    )),
+                    HumanTime::At(NaiveTime::from_hms_opt(10, 0, 0).unwrap())
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_ordinal_weekday_of_month_with_year() {
+        let mut parser = HumanDateParserBrazillianPortugueseParser::new();
+        assert_eq!(
+            parser.parse_peek("segundo domingo de setembro de 2026"),
+            Ok((
+                "",
+                HumanDateExpr::OrdinalWeekdayOfMonth(
+                    Ordinal::Second,
+                    Weekday::Sun,
+                    Month::September,
+                    Some(Year::Absolute(2026))
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("primeiro domingo de fevereiro do ano que vem"),
+            Ok((
+                "",
+                HumanDateExpr::OrdinalWeekdayOfMonth(
+                    Ordinal::First,
+                    Weekday::Sun,
+                    Month::February,
+                    Some(Year::Relative(1))
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("terceira quarta de maio deste ano"),
+            Ok((
+                "",
+                HumanDateExpr::OrdinalWeekdayOfMonth(
+                    Ordinal::Third,
+                    Weekday::Wed,
+                    Month::May,
+                    Some(Year::Relative(0))
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("primeira segunda de marco do ano passado"),
+            Ok((
+                "",
+                HumanDateExpr::OrdinalWeekdayOfMonth(
+                    Ordinal::First,
+                    Weekday::Mon,
+                    Month::March,
+                    Some(Year::Relative(-1))
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("primeira segunda de marco de manha"),
+            Ok((
+                "",
+                HumanDateExpr::DateTime(
+                    Box::new(HumanDateExpr::OrdinalWeekdayOfMonth(
+                        Ordinal::First,
+                        Weekday::Mon,
+                        Month::March,
+                        None
+                    )),
+                    HumanTime::Period(DayPeriod::Morning)
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_absolute() {
+        let mut parser = HumanDateParserBrazillianPortugueseParser::new();
+        assert_eq!(
+            parser.parse_peek("15 de agosto"),
+            Ok((
+                "",
+                HumanDateExpr::Absolute {
+                    day: 15,
+                    month: Some(Month::August),
+                    year: None
+                }
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("15 de agosto de 2025"),
+            Ok((
+                "",
+                HumanDateExpr::Absolute {
+                    day: 15,
+                    month: Some(Month::August),
+                    year: Some(2025)
+                }
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("dia 15"),
+            Ok((
+                "",
+                HumanDateExpr::Absolute {
+                    day: 15,
+                    month: None,
+                    year: None
+                }
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("dia 1º de maio"),
+            Ok((
+                "",
+                HumanDateExpr::Absolute {
+                    day: 1,
+                    month: Some(Month::May),
+                    year: None
+                }
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("15/08"),
+            Ok((
+                "",
+                HumanDateExpr::Absolute {
+                    day: 15,
+                    month: Some(Month::August),
+                    year: None
+                }
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("15/08/2024"),
+            Ok((
+                "",
+                HumanDateExpr::Absolute {
+                    day: 15,
+                    month: Some(Month::August),
+                    year: Some(2024)
+                }
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("15-08-24"),
+            Ok((
+                "",
+                HumanDateExpr::Absolute {
+                    day: 15,
+                    month: Some(Month::August),
+                    year: Some(2024)
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_business_days() {
+        let mut parser = HumanDateParserBrazillianPortugueseParser::new();
+        assert_eq!(
+            parser.parse_peek("em 5 dias uteis"),
+            Ok(("", HumanDateExpr::BusinessDays(5)))
+        );
+        assert_eq!(
+            parser.parse_peek("daqui a 1 dia util"),
+            Ok(("", HumanDateExpr::BusinessDays(1)))
+        );
+        assert_eq!(
+            parser.parse_peek("ha tres dias uteis"),
+            Ok(("", HumanDateExpr::BusinessDays(-3)))
+        );
+        assert_eq!(
+            parser.parse_peek("2 dias uteis atras"),
+            Ok(("", HumanDateExpr::BusinessDays(-2)))
+        );
+        assert_eq!(
+            parser.parse_peek("proximo dia util"),
+            Ok(("", HumanDateExpr::BusinessDays(1)))
+        );
+        assert_eq!(
+            parser.parse_peek("ultimo dia util do mes"),
+            Ok((
+                "",
+                HumanDateExpr::BusinessDayOfRelativeMonth(Ordinal::Last, 0)
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("o quinto dia util do mes que vem"),
+            Ok((
+                "",
+                HumanDateExpr::BusinessDayOfRelativeMonth(Ordinal::Fifth, 1)
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("em 5 dias"),
+            Ok(("", HumanDateExpr::InN(5, TimeUnit::Day)))
+        );
+    }
+
+    #[test]
+    fn test_relative_period() {
+        let mut parser = HumanDateParserBrazillianPortugueseParser::new();
+        assert_eq!(
+            parser.parse_peek("esta semana"),
+            Ok(("", HumanDateExpr::RelativePeriod(Period::Week, 0)))
+        );
+        assert_eq!(
+            parser.parse_peek("semana que vem"),
+            Ok(("", HumanDateExpr::RelativePeriod(Period::Week, 1)))
+        );
+        assert_eq!(
+            parser.parse_peek("proxima semana"),
+            Ok(("", HumanDateExpr::RelativePeriod(Period::Week, 1)))
+        );
+        assert_eq!(
+            parser.parse_peek("semana passada"),
+            Ok(("", HumanDateExpr::RelativePeriod(Period::Week, -1)))
+        );
+        assert_eq!(
+            parser.parse_peek("este mes"),
+            Ok(("", HumanDateExpr::RelativePeriod(Period::Month, 0)))
+        );
+        assert_eq!(
+            parser.parse_peek("mes que vem"),
+            Ok(("", HumanDateExpr::RelativePeriod(Period::Month, 1)))
+        );
+        assert_eq!(
+            parser.parse_peek("este fim de semana"),
+            Ok(("", HumanDateExpr::RelativePeriod(Period::Weekend, 0)))
+        );
+        assert_eq!(
+            parser.parse_peek("fim de semana"),
+            Ok(("", HumanDateExpr::RelativePeriod(Period::Weekend, 0)))
+        );
+        assert_eq!(
+            parser.parse_peek("ano que vem"),
+            Ok(("", HumanDateExpr::RelativePeriod(Period::Year, 1)))
+        );
+    }
+
+    #[test]
+    fn test_range() {
+        let mut parser = HumanDateParserBrazillianPortugueseParser::new();
+        assert_eq!(
+            parser.parse_peek("segunda até sexta"),
+            Ok((
+                "",
+                HumanDateExpr::Range(
+                    Box::new(HumanDateExpr::ThisWeekWeekday(Weekday::Mon)),
+                    Box::new(HumanDateExpr::ThisWeekWeekday(Weekday::Fri))
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("de segunda a sexta"),
+            Ok((
+                "",
+                HumanDateExpr::Range(
+                    Box::new(HumanDateExpr::ThisWeekWeekday(Weekday::Mon)),
+                    Box::new(HumanDateExpr::ThisWeekWeekday(Weekday::Fri))
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("entre 10 e 15 de maio"),
+            Ok((
+                "",
+                HumanDateExpr::Range(
+                    Box::new(HumanDateExpr::Absolute {
+                        day: 10,
+                        month: Some(Month::May),
+                        year: None
+                    }),
+                    Box::new(HumanDateExpr::Absolute {
+                        day: 15,
+                        month: Some(Month::May),
+                        year: None
+                    })
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("do dia 10 ao dia 15 de maio de 2025"),
+            Ok((
+                "",
+                HumanDateExpr::Range(
+                    Box::new(HumanDateExpr::Absolute {
+                        day: 10,
+                        month: Some(Month::May),
+                        year: Some(2025)
+                    }),
+                    Box::new(HumanDateExpr::Absolute {
+                        day: 15,
+                        month: Some(Month::May),
+                        year: Some(2025)
+                    })
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("desde ontem ate amanha"),
+            Ok((
+                "",
+                HumanDateExpr::Range(
+                    Box::new(HumanDateExpr::Keyword(HumanDateKeyword::Yesterday)),
+                    Box::new(HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow))
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_holiday() {
+        let mut parser = HumanDateParserBrazillianPortugueseParser::new();
+        assert_eq!(
+            parser.parse_peek("natal"),
+            Ok(("", HumanDateExpr::Holiday(Holiday::Christmas, None)))
+        );
+        assert_eq!(
+            parser.parse_peek("o ano novo"),
+            Ok(("", HumanDateExpr::Holiday(Holiday::NewYear, None)))
+        );
+        assert_eq!(
+            parser.parse_peek("pascoa de 2026"),
+            Ok((
+                "",
+                HumanDateExpr::Holiday(Holiday::Easter, Some(Year::Absolute(2026)))
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("carnaval do ano que vem"),
+            Ok((
+                "",
+                HumanDateExpr::Holiday(Holiday::Carnival, Some(Year::Relative(1)))
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("dia das maes"),
+            Ok(("", HumanDateExpr::Holiday(Holiday::MothersDay, None)))
+        );
+        assert_eq!(
+            parser.parse_peek("black friday"),
+            Ok(("", HumanDateExpr::Holiday(Holiday::BlackFriday, None)))
+        );
+        assert_eq!(
+            parser.parse_peek("sexta-feira santa"),
+            Ok(("", HumanDateExpr::Holiday(Holiday::GoodFriday, None)))
+        );
+        assert_eq!(
+            parser.parse_peek("sexta-feira"),
+            Ok(("", HumanDateExpr::ThisWeekWeekday(Weekday::Fri)))
+        );
+        assert_eq!(
+            parser.parse_peek("2 dias antes do natal"),
+            Ok((
+                "",
+                HumanDateExpr::Offset {
+                    base: Box::new(HumanDateExpr::Holiday(Holiday::Christmas, None)),
+                    delta: (2, TimeUnit::Day),
+                    direction: Direction::Before,
+                }
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("uma semana depois da black friday"),
+            Ok((
+                "",
+                HumanDateExpr::Offset {
+                    base: Box::new(HumanDateExpr::Holiday(Holiday::BlackFriday, None)),
+                    delta: (1, TimeUnit::Week),
+                    direction: Direction::After,
+                }
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("do natal ao ano novo"),
+            Ok((
+                "",
+                HumanDateExpr::Range(
+                    Box::new(HumanDateExpr::Holiday(Holiday::Christmas, None)),
+                    Box::new(HumanDateExpr::Holiday(Holiday::NewYear, None))
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_offset() {
+        let mut parser = HumanDateParserBrazillianPortugueseParser::new();
+        let offset = |base, delta, direction| HumanDateExpr::Offset {
+            base: Box::new(base),
+            delta,
+            direction,
+        };
+        assert_eq!(
+            parser.parse_peek("3 dias depois de amanha"),
+            Ok((
+                "",
+                offset(
+                    HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow),
+                    (3, TimeUnit::Day),
+                    Direction::After
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("30 dias apos o dia 10 de maio"),
+            Ok((
+                "",
+                offset(
+                    HumanDateExpr::Absolute {
+                        day: 10,
+                        month: Some(Month::May),
+                        year: None
+                    },
+                    (30, TimeUnit::Day),
+                    Direction::After
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("2 semanas antes da proxima sexta"),
+            Ok((
+                "",
+                offset(
+                    HumanDateExpr::NextWeekWeekday(Weekday::Fri),
+                    (2, TimeUnit::Week),
+                    Direction::Before
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("um mes apos amanha"),
+            Ok((
+                "",
+                offset(
+                    HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow),
+                    (1, TimeUnit::Month),
+                    Direction::After
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("uma semana depois de 2 dias antes do natal"),
+            Ok((
+                "",
+                offset(
+                    offset(
+                        HumanDateExpr::Holiday(Holiday::Christmas, None),
+                        (2, TimeUnit::Day),
+                        Direction::Before
+                    ),
+                    (1, TimeUnit::Week),
+                    Direction::After
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("2 dias depois de amanha as 10h"),
+            Ok((
+                "",
+                HumanDateExpr::DateTime(
+                    Box::new(offset(
+                        HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow),
+                        (2, TimeUnit::Day),
+                        Direction::After
+                    )),
+                    HumanTime::At(NaiveTime::from_hms_opt(10, 0, 0).unwrap())
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("depois de amanha"),
+            Ok(("", HumanDateExpr::Keyword(HumanDateKeyword::AfterTomorrow)))
+        );
+    }
+
+    #[test]
+    fn test_time() {
+        let mut parser = HumanDateParserBrazillianPortugueseParser::new();
+        let at = |hour, minute| HumanTime::At(NaiveTime::from_hms_opt(hour, minute, 0).unwrap());
+        assert_eq!(
+            parser.parse_peek("às 15h"),
+            Ok(("", HumanDateExpr::Time(at(15, 0))))
+        );
+        assert_eq!(
+            parser.parse_peek("às 15"),
+            Ok(("", HumanDateExpr::Time(at(15, 0))))
+        );
+        assert_eq!(
+            parser.parse_peek("às 3 horas"),
+            Ok(("", HumanDateExpr::Time(at(3, 0))))
+        );
+        assert_eq!(
+            parser.parse_peek("às 10, na sala"),
+            Ok((", na sala", HumanDateExpr::Time(at(10, 0))))
+        );
+        assert_eq!(
+            parser.parse_peek("15:30"),
+            Ok(("", HumanDateExpr::Time(at(15, 30))))
+        );
+        assert_eq!(
+            parser.parse_peek("15h30"),
+            Ok(("", HumanDateExpr::Time(at(15, 30))))
+        );
+        assert_eq!(
+            parser.parse_peek("meio-dia"),
+            Ok(("", HumanDateExpr::Time(at(12, 0))))
+        );
+        assert_eq!(
+            parser.parse_peek("à meia-noite"),
+            Ok(("", HumanDateExpr::Time(at(0, 0))))
+        );
+        assert_eq!(
+            parser.parse_peek("3 da tarde"),
+            Ok(("", HumanDateExpr::Time(at(15, 0))))
+        );
+        assert_eq!(
+            parser.parse_peek("às 8 da noite"),
+            Ok(("", HumanDateExpr::Time(at(20, 0))))
+        );
+        assert_eq!(
+            parser.parse_peek("de manhã"),
+            Ok((
+                "",
+                HumanDateExpr::Time(HumanTime::Period(DayPeriod::Morning))
+            ))
+        );
+    }
+
+    #[test]
+    fn test_date_time() {
+        let mut parser = HumanDateParserBrazillianPortugueseParser::new();
+        assert_eq!(
+            parser.parse_peek("amanhã às 10h"),
+            Ok((
+                "",
+                HumanDateExpr::DateTime(
+                    Box::new(HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow)),
+                    HumanTime::At(NaiveTime::from_hms_opt(10, 0, 0).unwrap())
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("amanhã de manhã"),
+            Ok((
+                "",
+                HumanDateExpr::DateTime(
+                    Box::new(HumanDateExpr::Keyword(HumanDateKeyword::Tomorrow)),
+                    HumanTime::Period(DayPeriod::Morning)
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("próxima sexta às 3 da tarde"),
+            Ok((
+                "",
+                HumanDateExpr::DateTime(
+                    Box::new(HumanDateExpr::NextWeekWeekday(Weekday::Fri)),
+                    HumanTime::At(NaiveTime::from_hms_opt(15, 0, 0).unwrap())
+                )
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("15/08 15:30"),
+            Ok((
+                "",
+                HumanDateExpr::DateTime(
+                    Box::new(HumanDateExpr::Absolute {
+                        day: 15,
+                        month: Some(Month::August),
+                        year: None
+                    }),
+                    HumanTime::At(NaiveTime::from_hms_opt(15, 30, 0).unwrap())
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_weekday() {
+        assert_eq!(weekday.parse_peek("segunda-feira"), Ok(("", Weekday::Mon)));
+        assert_eq!(weekday.parse_peek("segunda feira"), Ok(("", Weekday::Mon)));
+        assert_eq!(weekday.parse_peek("seg."), Ok(("", Weekday::Mon)));
+        assert_eq!(weekday.parse_peek("seg"), Ok(("", Weekday::Mon)));
+        assert_eq!(weekday.parse_peek("terca-feira"), Ok(("", Weekday::Tue)));
+        assert_eq!(weekday.parse_peek("terca feira"), Ok(("", Weekday::Tue)));
+        assert_eq!(weekday.parse_peek("ter."), Ok(("", Weekday::Tue)));
+        assert_eq!(weekday.parse_peek("ter"), Ok(("", Weekday::Tue)));
+        assert_eq!(weekday.parse_peek("quarta-feira"), Ok(("", Weekday::Wed)));
+        assert_eq!(weekday.parse_peek("quarta feira"), Ok(("", Weekday::Wed)));
+        assert_eq!(weekday.parse_peek("qua."), Ok(("", Weekday::Wed)));
+        assert_eq!(weekday.parse_peek("qua"), Ok(("", Weekday::Wed)));
+        assert_eq!(weekday.parse_peek("quinta-feira"), Ok(("", Weekday::Thu)));
+        assert_eq!(weekday.parse_peek("quinta feira"), Ok(("", Weekday::Thu)));
+        assert_eq!(weekday.parse_peek("qui."), Ok(("", Weekday::Thu)));
+        assert_eq!(weekday.parse_peek("qui"), Ok(("", Weekday::Thu)));
+        assert_eq!(weekday.parse_peek("sexta-feira"), Ok(("", Weekday::Fri)));
+        assert_eq!(weekday.parse_peek("sexta feira"), Ok(("", Weekday::Fri)));
+        assert_eq!(weekday.parse_peek("sex."), Ok(("", Weekday::Fri)));
+        assert_eq!(weekday.parse_peek("sex"), Ok(("", Weekday::Fri)));
+        assert_eq!(weekday.parse_peek("sabado"), Ok(("", Weekday::Sat)));
+        assert_eq!(weekday.parse_peek("sab."), Ok(("", Weekday::Sat)));
+        assert_eq!(weekday.parse_peek("sab"), Ok(("", Weekday::Sat)));
+        for (input, expected) in [
+            ("terça-feira", Weekday::Tue),
+            ("terça feira", Weekday::Tue),
+            ("sábado", Weekday::Sat),
+            ("sáb.", Weekday::Sat),
+            ("sáb", Weekday::Sat),
+        ] {
+            assert_eq!(
+                parse(&mut &*input, &Locale::BrazilianPortuguese),
+                Ok(HumanDateExpr::ThisWeekWeekday(expected))
+            );
+        }
+        assert_eq!(weekday.parse_peek("domingo"), Ok(("", Weekday::Sun)));
+        assert_eq!(weekday.parse_peek("dom."), Ok(("", Weekday::Sun)));
+        assert_eq!(weekday.parse_peek("dom"), Ok(("", Weekday::Sun)));
+    }
+
+    #[test]
+    fn test_this() {
+        assert_eq!(this.parse_peek("esta"), Ok(("", ())));
+        assert_eq!(this.parse_peek("essa"), Ok(("", ())));
+        assert_eq!(this.parse_peek("esse"), Ok(("", ())));
+        assert_eq!(this.parse_peek("este"), Ok(("", ())));
+        assert!(this.parse_peek("esa").is_err());
+        assert!(this.parse_peek("ese").is_err());
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(ordinal.parse_peek("primeira"), Ok(("", Ordinal::First)));
+        assert_eq!(ordinal.parse_peek("segundo"), Ok(("", Ordinal::Second)));
+        assert_eq!(ordinal.parse_peek("terceira"), Ok(("", Ordinal::Third)));
+        assert_eq!(ordinal.parse_peek("quarto"), Ok(("", Ordinal::Fourth)));
+        assert_eq!(ordinal.parse_peek("quinta"), Ok(("", Ordinal::Fifth)));
+        assert_eq!(ordinal.parse_peek("ultimo"), Ok(("", Ordinal::Last)));
+        assert_eq!(
+            ordinal.parse_peek("penultima"),
+            Ok(("", Ordinal::SecondToLast))
+        );
+        assert!(ordinal.parse_peek("primer").is_err());
+        assert!(ordinal.parse_peek("cuarto").is_err());
+    }
+
+    #[test]
+    fn test_next() {
+        assert_eq!(next.parse_peek("proxima"), Ok(("", ())));
+        assert_eq!(next.parse_peek("proximo"), Ok(("", ())));
+        assert_eq!(next.parse_peek("prox."), Ok(("", ())));
+        assert_eq!(next.parse_peek("prox"), Ok(("", ())));
+        for input in ["próxima sexta", "próximo domingo", "próx. domingo"] {
+            assert!(matches!(
+                parse(&mut &*input, &Locale::BrazilianPortuguese),
+                Ok(HumanDateExpr::NextWeekWeekday(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_number() {
+        assert_eq!(number(&mut "1"), Ok(1));
+        assert_eq!(number(&mut "01"), Ok(1));
         assert_eq!(number(&mut "um"), Ok(1));
+        assert_eq!(number(&mut "uma"), Ok(1));
         assert_eq!(number(&mut "dois"), Ok(2));
-        assert_eq!(number(&mut "três"), Ok(3));
+        assert_eq!(number(&mut "duas"), Ok(2));
+        assert_eq!(number(&mut "tres"), Ok(3));
+        assert_eq!(
+            parse(&mut "em três dias", &Locale::BrazilianPortuguese),
+            Ok(HumanDateExpr::InN(3, TimeUnit::Day))
+        );
         assert_eq!(number(&mut "quatro"), Ok(4));
         assert_eq!(number(&mut "cinco"), Ok(5));
         assert_eq!(number(&mut "seis"), Ok(6));
@@ -0,0 +1,236 @@
+use chrono::Weekday;
+use winnow::{
+    ascii::space1,
+    combinator::{alt, preceded},
+    Parser,
+};
+
+use super::{parse_folded, PResult};
+use crate::error::GrammarError;
+
+use super::pt_br::{self, weekday, Dialect};
+use crate::{HumanDateExpr, Period, TimeUnit};
+
+/// Words this grammar adds to [`pt_br::VOCABULARY`].
+pub(crate) const VOCABULARY: &[&str] = &["dentro", "dezasseis", "dezassete", "dezanove", "para"];
+
+#[derive(Default)]
+pub struct HumanDateParserEuropeanPortugueseParser;
+
+impl HumanDateParserEuropeanPortugueseParser {
+    pub fn new() -> Self {
+        HumanDateParserEuropeanPortugueseParser {}
+    }
+}
+
+impl Parser<&str, HumanDateExpr, GrammarError> for HumanDateParserEuropeanPortugueseParser {
+    fn parse_next(&mut self, input: &mut &str) -> PResult<HumanDateExpr> {
+        parse_folded(input, grammar)
+    }
+}
+
+/// The grammar, which only matches folded text.
+pub(crate) fn grammar(input: &mut &str) -> PResult<HumanDateExpr> {
+    pt_br::portuguese(EUROPEAN).parse_next(input)
+}
+
+/// Where pt-PT differs from [`pt_br`].
+const EUROPEAN: Dialect = Dialect {
+    number,
+    in_n,
+    relative_period,
+    weekday_of_next_week,
+};
+
+fn in_n(input: &mut &str) -> PResult<(u64, TimeUnit)> {
+    preceded(
+        (alt(("daqui a", "dentro de", "daqui", "em")), space1),
+        pt_br::quantity(number),
+    )
+    .parse_next(input)
+}
+
+/// "para a semana", "na próxima semana", "no mês que vem"...
+fn relative_period(input: &mut &str) -> PResult<(Period, i32)> {
+    alt((
+        "para a semana".value((Period::Week, 1)),
+        preceded((alt(("na", "no")), space1), pt_br::relative_period),
+        pt_br::relative_period,
+    ))
+    .parse_next(input)
+}
+
+fn weekday_of_next_week(input: &mut &str) -> PResult<Weekday> {
+    alt((
+        pt_br::weekday_of_next_week,
+        (weekday, space1, "para a semana").map(|(weekday, _, _)| weekday),
+    ))
+    .parse_next(input)
+}
+
+fn number(input: &mut &str) -> PResult<u64> {
+    alt((
+        "dezassete".value(17),
+        "dezasseis".value(16),
+        "dezanove".value(19),
+        pt_br::number,
+    ))
+    .parse_next(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Direction, Holiday, HumanDateExpr, HumanDateKeyword, Period, TimeUnit};
+    use chrono::Weekday;
+    use winnow::Parser;
+
+    use super::{number, HumanDateParserEuropeanPortugueseParser};
+
+    #[test]
+    fn test_keywords() {
+        let mut parser = HumanDateParserEuropeanPortugueseParser::new();
+        assert_eq!(
+            parser.parse_peek("hoje"),
+            Ok(("", HumanDateExpr::Keyword(HumanDateKeyword::Today)))
+        );
+        assert_eq!(
+            parser.parse_peek("depois de amanhã"),
+            Ok(("", HumanDateExpr::Keyword(HumanDateKeyword::AfterTomorrow)))
+        );
+        assert_eq!(
+            parser.parse_peek("anteontem"),
+            Ok((
+                "",
+                HumanDateExpr::Keyword(HumanDateKeyword::BeforeYesterday)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_n_days_ago() {
+        let mut parser = HumanDateParserEuropeanPortugueseParser::new();
+        assert_eq!(
+            parser.parse_peek("há dezassete dias"),
+            Ok(("", HumanDateExpr::NAgo(17, TimeUnit::Day)))
+        );
+        assert_eq!(
+            parser.parse_peek("3 dias atrás"),
+            Ok(("", HumanDateExpr::NAgo(3, TimeUnit::Day)))
+        );
+    }
+
+    #[test]
+    fn test_in_n_days() {
+        let mut parser = HumanDateParserEuropeanPortugueseParser::new();
+        assert_eq!(
+            parser.parse_peek("daqui a 3 dias"),
+            Ok(("", HumanDateExpr::InN(3, TimeUnit::Day)))
+        );
+        assert_eq!(
+            parser.parse_peek("dentro de dezasseis dias"),
+            Ok(("", HumanDateExpr::InN(16, TimeUnit::Day)))
+        );
+        assert_eq!(
+            parser.parse_peek("em dois dias"),
+            Ok(("", HumanDateExpr::InN(2, TimeUnit::Day)))
+        );
+    }
+
+    #[test]
+    fn test_in_n_units() {
+        let mut parser = HumanDateParserEuropeanPortugueseParser::new();
+        assert_eq!(
+            parser.parse_peek("daqui a duas semanas"),
+            Ok(("", HumanDateExpr::InN(2, TimeUnit::Week)))
+        );
+        assert_eq!(
+            parser.parse_peek("há dezanove anos"),
+            Ok(("", HumanDateExpr::NAgo(19, TimeUnit::Year)))
+        );
+    }
+
+    #[test]
+    fn test_relative_period() {
+        let mut parser = HumanDateParserEuropeanPortugueseParser::new();
+        assert_eq!(
+            parser.parse_peek("para a semana"),
+            Ok(("", HumanDateExpr::RelativePeriod(Period::Week, 1)))
+        );
+        assert_eq!(
+            parser.parse_peek("na proxima semana"),
+            Ok(("", HumanDateExpr::RelativePeriod(Period::Week, 1)))
+        );
+        assert_eq!(
+            parser.parse_peek("no mes que vem"),
+            Ok(("", HumanDateExpr::RelativePeriod(Period::Month, 1)))
+        );
+        assert_eq!(
+            parser.parse_peek("esta semana"),
+            Ok(("", HumanDateExpr::RelativePeriod(Period::Week, 0)))
+        );
+    }
+
+    #[test]
+    fn test_shared_with_pt_br() {
+        let mut parser = HumanDateParserEuropeanPortugueseParser::new();
+        assert_eq!(
+            parser.parse_peek("Natal"),
+            Ok(("", HumanDateExpr::Holiday(Holiday::Christmas, None)))
+        );
+        assert_eq!(
+            parser.parse_peek("dezassete dias antes do Natal"),
+            Ok((
+                "",
+                HumanDateExpr::Offset {
+                    base: Box::new(HumanDateExpr::Holiday(Holiday::Christmas, None)),
+                    delta: (17, TimeUnit::Day),
+                    direction: Direction::Before,
+                }
+            ))
+        );
+        assert_eq!(
+            parser.parse_peek("daqui a dezanove dias úteis"),
+            Ok(("", HumanDateExpr::BusinessDays(19)))
+        );
+    }
+
+    #[test]
+    fn test_next_week_weekday() {
+        let mut parser = HumanDateParserEuropeanPortugueseParser::new();
+        assert_eq!(
+            parser.parse_peek("segunda-feira da próxima semana"),
+            Ok(("", HumanDateExpr::WeekdayOfNextWeek(Weekday::Mon)))
+        );
+        assert_eq!(
+            parser.parse_peek("sexta na semana que vem"),
+            Ok(("", HumanDateExpr::WeekdayOfNextWeek(Weekday::Fri)))
+        );
+        assert_eq!(
+            parser.parse_peek("quarta para a semana"),
+            Ok(("", HumanDateExpr::WeekdayOfNextWeek(Weekday::Wed)))
+        );
+        assert_eq!(
+            parser.parse_peek("próximo sábado"),
+            Ok(("", HumanDateExpr::NextWeekWeekday(Weekday::Sat)))
+        );
+    }
+
+    #[test]
+    fn test_this_week_weekday() {
+        let mut parser = HumanDateParserEuropeanPortugueseParser::new();
+        assert_eq!(
+            parser.parse_peek("esta terça-feira"),
+            Ok(("", HumanDateExpr::ThisWeekWeekday(Weekday::Tue)))
+        );
+    }
+
+    #[test]
+    fn test_number() {
+        assert_eq!(number(&mut "catorze"), Ok(14));
+        assert_eq!(number(&mut "dezasseis"), Ok(16));
+        assert_eq!(number(&mut "dezassete"), Ok(17));
+        assert_eq!(number(&mut "dezanove"), Ok(19));
+        assert_eq!(number(&mut "dezoito"), Ok(18));
+        assert_eq!(number(&mut "cinco"), Ok(5));
+    }
+}
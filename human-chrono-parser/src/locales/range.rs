@@ -0,0 +1,73 @@
+//! Date ranges, shared by every locale. They all join two dates the same way,
+//! only with their own words: "de segunda a sexta", "between may 10 and 15".
+
+use winnow::{
+    ascii::space1,
+    combinator::{alt, fail},
+    token::literal,
+    Parser,
+};
+
+use crate::{error::GrammarError, HumanDateExpr, RangeBound};
+
+/// The words that join the two bounds of a range, each list ordered so that no
+/// word comes after a prefix of it.
+pub(crate) struct Connectives {
+    /// Before the start, followed by one of `to`: "de", "from".
+    pub(crate) from: &'static [&'static str],
+    pub(crate) to: &'static [&'static str],
+    /// Before the start, followed by `and`: "entre", "between".
+    pub(crate) between: &'static str,
+    pub(crate) and: &'static str,
+    /// Between the bounds, with nothing before the start: "até", "through".
+    pub(crate) until: &'static [&'static str],
+}
+
+/// A range of `date`s, or of `day`s of a month that the other bound names,
+/// as in "entre 10 e 15 de maio".
+pub(crate) fn range<'a>(
+    date: impl Parser<&'a str, HumanDateExpr, GrammarError> + Copy,
+    day: impl Parser<&'a str, u32, GrammarError> + Copy,
+    connectives: &'static Connectives,
+) -> impl Parser<&'a str, HumanDateExpr, GrammarError> {
+    let bound = move |input: &mut &'a str| {
+        alt((date.map(RangeBound::Date), day.map(RangeBound::Day))).parse_next(input)
+    };
+    alt((
+        (
+            word(connectives.from),
+            space1,
+            bound,
+            space1,
+            word(connectives.to),
+            space1,
+            bound,
+        )
+            .map(|(_, _, start, _, _, _, end)| (start, end)),
+        (
+            connectives.between,
+            space1,
+            bound,
+            space1,
+            connectives.and,
+            space1,
+            bound,
+        )
+            .map(|(_, _, start, _, _, _, end)| (start, end)),
+        (bound, space1, word(connectives.until), space1, bound)
+            .map(|(start, _, _, _, end)| (start, end)),
+    ))
+    .verify_map(|(start, end)| HumanDateExpr::range(start, end))
+}
+
+/// The first of `words` that `input` starts with.
+fn word<'a>(words: &'static [&'static str]) -> impl Parser<&'a str, &'a str, GrammarError> + Copy {
+    move |input: &mut &'a str| {
+        for word in words {
+            if let Ok(word) = literal::<_, _, GrammarError>(*word).parse_next(input) {
+                return Ok(word);
+            }
+        }
+        fail.parse_next(input)
+    }
+}
@@ -0,0 +1,96 @@
+//! Case and diacritic folding applied to the input before it reaches the locale
+//! grammars, so they only need to spell each word once, in lowercase and without
+//! accents: "Amanhã", "AMANHA" and "amanhã" all become "amanha".
+//!
+//! The one accent kept is the grave on "à", which in Portuguese marks the
+//! contraction of the preposition "a" with an article: "às 3" is a time, while
+//! "as 3 lojas" only counts stores.
+
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+const GRAVE: char = '\u{300}';
+
+/// Folded copy of a source text that remembers where each byte came from.
+pub(crate) struct Folded {
+    text: String,
+    /// Source offset of the char each folded byte was produced by, plus one
+    /// trailing entry for the end of the text.
+    origins: Vec<usize>,
+}
+
+impl Folded {
+    pub(crate) fn new(source: &str) -> Self {
+        let mut text = String::with_capacity(source.len());
+        let mut origins = Vec::with_capacity(source.len() + 1);
+        for (offset, c) in source.char_indices() {
+            for folded in c.to_lowercase().nfd() {
+                if folded == GRAVE && text.ends_with('a') {
+                    text.pop();
+                    text.push('à');
+                    origins.resize(text.len(), origins[text.len() - 2]);
+                } else if !is_combining_mark(folded) {
+                    text.push(folded);
+                    origins.resize(text.len(), offset);
+                }
+            }
+        }
+        origins.push(source.len());
+        Folded { text, origins }
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Maps a byte offset in the folded text back to the source text. Offsets
+    /// at the end of a char map past any combining marks that were dropped
+    /// after it, so a span like "amanha" covers all of "amanhã".
+    pub(crate) fn source_offset(&self, offset: usize) -> usize {
+        self.origins[offset]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Folded;
+
+    #[test]
+    fn test_fold() {
+        assert_eq!(Folded::new("Amanhã").as_str(), "amanha");
+        assert_eq!(Folded::new("PRÓXIMA Terça").as_str(), "proxima terca");
+        assert_eq!(Folded::new("miércoles 15:30").as_str(), "miercoles 15:30");
+        assert_eq!(Folded::new("mañana").as_str(), "manana");
+        assert_eq!(Folded::new("ÀS 3, às 4").as_str(), "às 3, às 4");
+        assert_eq!(Folded::new("vamos as lojas").as_str(), "vamos as lojas");
+    }
+
+    #[test]
+    fn test_source_offset() {
+        let source = "até Amanhã às 10h";
+        let folded = Folded::new(source);
+        assert_eq!(folded.as_str(), "ate amanha às 10h");
+        assert_eq!(folded.source_offset(3), 4);
+        assert_eq!(
+            &source[folded.source_offset(4)..folded.source_offset(10)],
+            "Amanhã"
+        );
+        assert_eq!(
+            &source[folded.source_offset(11)..folded.source_offset(18)],
+            "às 10h"
+        );
+
+        // Already decomposed input: "a" followed by a combining tilde.
+        let source = "amanha\u{303} cedo";
+        let folded = Folded::new(source);
+        assert_eq!(folded.as_str(), "amanha cedo");
+        assert_eq!(&source[..folded.source_offset(6)], "amanha\u{303}");
+
+        let source = "a\u{300}s 3";
+        let folded = Folded::new(source);
+        assert_eq!(folded.as_str(), "às 3");
+        assert_eq!(
+            &source[folded.source_offset(0)..folded.source_offset(3)],
+            "a\u{300}s"
+        );
+    }
+}